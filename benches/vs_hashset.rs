@@ -13,6 +13,7 @@ fn benchmarks(c: &mut Criterion)
 {
     construct(c);
     insert(c);
+    contains(c);
     intersection(c);
 }
 
@@ -32,7 +33,7 @@ fn construct(c: &mut Criterion)
 {
     group!("construction - empty ", c =>
         " HashSet::from([])"     => HashSet::<usize>::from([]),
-        " Bitset::<9>::from([])" => Bitset::<9>::from([0; 0]),
+        " Bitset::<9>::from([])" => Bitset::<9, u16>::from([0; 0]),
         " Bitset::<9>::none()"   => Bitset::<9, u16>::none(),
         " Bitset::<9>::all()"    => Bitset::<9, u16>::all(),
     );
@@ -43,10 +44,10 @@ fn construct(c: &mut Criterion)
     );
 
     group!("construction - large ", c =>
-        " HashSet::from_iter(1..=65536)" => (1..=65536).collect::<HashSet<usize>>(),
-        " Bitset::from_iter(1..=65536)"  => (1..=65536).collect::<Bitset::<65536, u32>>(),
-        " Bitset::<65536>::none()"       => Bitset::<65536, u32>::none(),
-        " Bitset::<65536>::all()"        => Bitset::<65536, u32>::all(),
+        " HashSet::from_iter(1..=128)" => (1..=128).collect::<HashSet<usize>>(),
+        " Bitset::from_iter(1..=128)"  => (1..=128).collect::<Bitset::<128, u128>>(),
+        " Bitset::<128>::none()"       => Bitset::<128, u128>::none(),
+        " Bitset::<128>::all()"        => Bitset::<128, u128>::all(),
     );
 
     // VERY LARGE
@@ -68,6 +69,19 @@ fn insert(c: &mut Criterion)
     );
 }
 
+fn contains(c: &mut Criterion)
+{
+    let s = (1..=128).step_by(2).collect::<HashSet<usize>>();
+    let b = (1..=128).step_by(2).collect::<Bitset<128, u128>>();
+
+    group!("contains - large ", c =>
+        " HashSet[64].contains(127)"    => s.contains(&127),
+        " Bitset[64].iter().any(127)"   => b.iter().any(|n| n == 127),
+        " Bitset[64].contains(127)"     => b.contains(&127),
+        " Bitset[64].has(127)"          => b.has(127),
+    );
+}
+
 fn intersection(c: &mut Criterion)
 {
     let ls = HashSet::from([1,2,4]);
@@ -82,15 +96,20 @@ fn intersection(c: &mut Criterion)
         " Bitset[3] &"               => lb & rb,
     );
 
-    let ls = (1..=42069).collect::<HashSet<_>>();
-    let rs = (1729..=69420).collect::<HashSet<_>>();
+    group!("intersect count - small ", c =>
+        " HashSet[3].intersection().count()" => ls.intersection(&rs).count(),
+        " Bitset[3].common_count()"          => lb.common_count(&rb),
+    );
+
+    let ls = (1..=80).collect::<HashSet<_>>();
+    let rs = (42..=128).collect::<HashSet<_>>();
 
-    let lb = (1..=42069).collect::<Bitset<69420, u32>>();
-    let rb = (1729..=69420).collect::<Bitset<69420, u32>>();
+    let lb = (1..=80).collect::<Bitset<128, u128>>();
+    let rb = (42..=128).collect::<Bitset<128, u128>>();
 
     group!("intersect - large ", c =>
-        " HashSet[42069].intersection()" => ls.intersection(&rs).collect::<HashSet<_>>(),
-        " Bitset[42069].intersection()"  => lb.intersection(&rb),
-        " Bitset[42069] &"               => lb & rb,
+        " HashSet[80].intersection()" => ls.intersection(&rs).collect::<HashSet<_>>(),
+        " Bitset[80].intersection()"  => lb.intersection(&rb),
+        " Bitset[80] &"               => lb & rb,
     );
 }
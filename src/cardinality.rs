@@ -0,0 +1,44 @@
+use std::*;
+
+use crate::*;
+
+
+/// A wrapper around a [`Bitset`] which orders sets by size.
+/// 
+/// Sets are ordered by how many integers they contain (fewest first), with ties broken by comparing the underlying integers. Hence this is a total ordering, unlike the subset ordering of [`Bitset`] itself.
+/// 
+/// This is useful for prioritising sets with the fewest elements, such as Sudoku cells with the fewest candidates.
+/// 
+/// # Usage
+/// 
+/// ```rust
+/// # use natbitset::*;
+/// use std::cmp::Reverse;
+/// use std::collections::BinaryHeap;
+/// 
+/// let mut cells = BinaryHeap::new();
+/// cells.push(Reverse(ByCardinality(byteset![1,2,3])));
+/// cells.push(Reverse(ByCardinality(byteset![4,5])));
+/// cells.push(Reverse(ByCardinality(byteset![6,7,8])));
+/// 
+/// let Reverse(ByCardinality(fewest)) = cells.pop().unwrap();
+/// assert_eq!(fewest, byteset![4,5]);
+/// ```
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct ByCardinality<const N: usize, Z = u8>(pub Bitset<N,Z>)
+    where Z: PosInt;
+
+impl<Z: PosInt, const N: usize> Ord for ByCardinality<N,Z> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let (left, right) = (self.0.canonical(), other.0.canonical());
+
+        left.count_ones().cmp(&right.count_ones())
+            .then_with(|| left.0.cmp(&right.0))
+    }
+}
+
+impl<Z: PosInt, const N: usize> PartialOrd for ByCardinality<N,Z> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
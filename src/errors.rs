@@ -14,3 +14,55 @@ impl fmt::Display for EmptiedBitsetError
 }
 
 impl Error for EmptiedBitsetError {}
+
+
+/// An error where parsing a [`Bitset`](crate::Bitset) from a string failed.
+#[derive(Clone, Debug)]
+pub struct ParseBitsetError(pub String);
+
+impl fmt::Display for ParseBitsetError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for ParseBitsetError {}
+
+
+/// An error where a [`Bitset`](crate::Bitset) contained more elements than allowed.
+#[derive(Clone, Debug)]
+pub struct TooManyError {
+    /// How many elements the set contained.
+    pub len: usize,
+    /// The maximum number of elements allowed.
+    pub max: usize,
+}
+
+impl fmt::Display for TooManyError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bitset contains {} elements, but at most {} are allowed", self.len, self.max)
+    }
+}
+
+impl Error for TooManyError {}
+
+
+/// An error where the integer type `Z` of a [`Bitset`](crate::Bitset) has too few bits to represent integers `1..=N`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapacityError {
+    /// The range `1..=n` of integers the set should represent.
+    pub n: usize,
+    /// The number of bits in `Z`.
+    pub z_bits: usize,
+}
+
+impl fmt::Display for CapacityError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bitset needs {} bits to represent integers `1..={}`, but `Z` only has {}", self.n, self.n, self.z_bits)
+    }
+}
+
+impl Error for CapacityError {}
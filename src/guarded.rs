@@ -0,0 +1,97 @@
+use std::*;
+
+use crate::*;
+
+
+/// A wrapper around a [`Bitset`] which can never contain bits above `N`.
+/// 
+/// [`Bitset`] dereferences mutably to its underlying integer, so raw mutation like `*bitset <<= 1` can enable bits representing integers beyond `N`. `Guarded` only dereferences immutably, and its mutators always clear any bits above `N` afterwards.
+/// 
+/// The trade-off is that you lose direct access to the underlying integer, and each mutation pays for an extra mask. If you need raw bit manipulation, use [`Bitset`] and call [`canonical`](Bitset::canonical) where necessary.
+/// 
+/// # Usage
+/// 
+/// ```rust
+/// # use natbitset::*;
+/// let mut cell = Guarded::new(byteset![1,2,3]);
+/// 
+/// cell.update(|bitset| **bitset <<= 6);
+/// assert_eq!( *cell.get(), 0b_1100_0000 );
+/// 
+/// cell.insert(4);
+/// assert_eq!( cell.get(), byteset![4,7,8] );
+/// ```
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Default, Debug)]
+pub struct Guarded<const N: usize, Z = u8>(Bitset<N,Z>)
+    where Z: PosInt;
+
+impl<Z: PosInt, const N: usize> Guarded<N,Z>
+{
+    /// Wrap `bitset`, clearing any bits above `N`.
+    pub fn new(bitset: Bitset<N,Z>) -> Self {
+        Self(bitset.canonical())
+    }
+
+    /// Get the wrapped set.
+    pub fn get(self) -> Bitset<N,Z> {
+        self.0
+    }
+
+    /// Mutate the wrapped set through `f`, then clear any bits above `N`.
+    pub fn update(&mut self, f: impl FnOnce(&mut Bitset<N,Z>))
+    {
+        f(&mut self.0);
+        self.0 = self.0.canonical();
+    }
+
+    /// Add `int` to the set. Returns whether the integer was newly inserted.
+    /// 
+    /// See [`Bitset::insert`] for more info.
+    pub fn insert<R>(&mut self, int: R) -> bool
+        where R: AnyInt
+    {
+        self.0.insert(int)
+    }
+
+    /// Remove `int` from the set. Returns whether the integer was present.
+    /// 
+    /// See [`Bitset::remove`] for more info.
+    pub fn remove<R>(&mut self, int: &R) -> bool
+        where R: AnyInt
+    {
+        self.0.remove(int)
+    }
+
+    /// Filter the set to keep only elements that fulfil `predicate`.
+    /// 
+    /// See [`Bitset::retain`] for more info.
+    pub fn retain(&mut self, predicate: impl FnMut(usize) -> bool)
+    {
+        self.update(|bitset| bitset.retain(predicate));
+    }
+
+    /// Remove all elements from the set.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl<Z: PosInt, const N: usize> ops::Deref for Guarded<N,Z> {
+    type Target = Bitset<N,Z>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Z: PosInt, const N: usize> From<Bitset<N,Z>> for Guarded<N,Z> {
+    fn from(bitset: Bitset<N,Z>) -> Self {
+        Self::new(bitset)
+    }
+}
+
+impl<Z: PosInt, const N: usize> From<Guarded<N,Z>> for Bitset<N,Z> {
+    fn from(guarded: Guarded<N,Z>) -> Self {
+        guarded.0
+    }
+}
@@ -0,0 +1,48 @@
+use bitvec::prelude::*;
+
+use crate::*;
+
+
+/// Interop with the [`bitvec`] crate, enabled by the `bitvec` feature.
+impl<Z: PosInt, const N: usize> Bitset<N,Z>
+{
+    /// Convert the set into a [`BitVec`] of length `N`, where index `i` indicates whether `i+1` is in the set.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bits = Bitset::<4>::from([1,3]).to_bitvec();
+    /// 
+    /// assert_eq!(bits.len(), 4);
+    /// assert!(bits[0] && !bits[1] && bits[2] && !bits[3]);
+    /// ```
+    pub fn to_bitvec(self) -> BitVec
+    {
+        self.flags().collect()
+    }
+
+    /// Construct a set from a [`BitSlice`], where index `i` indicates whether `i+1` is in the set. Bits beyond index `N-1` are ignored.
+    /// 
+    /// This is the reverse of [`to_bitvec`](Self::to_bitvec).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// use bitvec::prelude::*;
+    /// 
+    /// let bitset = Bitset::<4>::from_bitvec(bits![1, 0, 1, 0, 1, 1]);
+    /// assert_eq!(bitset, Bitset::<4>::from([1,3]));
+    /// ```
+    pub fn from_bitvec(bits: &BitSlice) -> Self
+    {
+        let mut out = Self::none();
+
+        for i in bits.iter_ones().take_while(|&i| i < N) {
+            out.insert_usize(i+1);
+        }
+
+        out
+    }
+}
@@ -36,9 +36,17 @@
 mod natbitset;
 pub use natbitset::{
     Bitset,
+    AsFlags,
+    FlagsIterator,
+    MaskOp,
 };
 
+mod cardinality; pub use cardinality::ByCardinality;
+mod guarded; pub use guarded::Guarded;
+mod summary; pub use summary::BitsetSummary;
 mod traits; pub use traits::*;
 mod errors; pub use errors::*;
 
+#[cfg(feature = "bitvec")] mod interop;
+
 pub(crate) mod util;
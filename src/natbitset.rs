@@ -17,15 +17,20 @@ use crate::util::boxerr;
 /// - `N` (required): The maximum integer represented by the set.
 ///   - A `Bitset<N, _>` represents integers `1..=N`, and will ignore integers outside this range.
 /// - `Z` (optional): The unsigned integer type used to store the bitflags (e.g. `u8`, `u16`, `usize`).
-///   - Defaults to `u8`, which allows the set to represent integers `1..=256`, which should be more than enough to cover most use cases.
+///   - Defaults to `u8`, which allows the set to represent integers `1..=8`. `Z` must have at least `N` bits, so use a larger type for larger `N`.
 /// 
 /// ## Notes
 /// 
 /// - A subtle distinction is that `Z` dictates how many integers the bitset *could* represent, while `N` tells the struct and programmer how many it actually *does* represent.
 /// - To optimise space efficiency, you should make `Z` as small as possible for your use case `N`.
-///   - However, if you make it too small such that it can’t represent integers up to `N`, you’ll likely encounter overflow errors caused by bitshifting.[^overflow]
+///   - However, `Z` must have at least `N` bits. Constructing a `Bitset` whose `Z` is too small for `N` (such as `Bitset<9, u8>`) with any of its constructors fails to compile.[^capacity]
 /// 
-/// [^overflow]: This will hopefully be remedied in future.
+/// [^capacity]: Since the underlying integer is public, `Bitset::<9, u8>(0)` can still be written directly. This is not recommended!
+/// 
+/// ```rust,compile_fail,E0080
+/// # use natbitset::*;
+/// let bitset = Bitset::<9, u8>::all();
+/// ```
 /// 
 /// # Usage
 /// 
@@ -49,8 +54,8 @@ use crate::util::boxerr;
 /// // or even more conveniently:
 /// let bitset = byteset![1;8];
 /// 
-/// // A bitset representing numbers 1..=1000 (need a larger `Z`!)
-/// let bitset = Bitset::<1000, u16>::none();
+/// // A bitset representing numbers 1..=100 (need a larger `Z`!)
+/// let bitset = Bitset::<100, u128>::none();
 /// 
 /// // Or instantiate manually, passing the bit representation directly:
 /// let bitset = Bitset::<4>(0b_0101);
@@ -134,7 +139,7 @@ use crate::util::boxerr;
 /// 
 /// - `Bitset` is **much** more lightweight than `HashSet` – it's only a single integer!
 ///   - `Bitset` implements `Copy`, so you can pass it around without borrowing.
-#[derive(Copy, Clone, Hash, PartialEq, Eq, Default)]
+#[derive(Copy, Clone)]
 pub struct Bitset<const N: usize, Z = u8>(
     /// The underlying integer used to represent the set. When written in binary, each bit represents whether a number is present in the set (`1` if present, `0` if not).
     /// 
@@ -154,6 +159,9 @@ pub struct Bitset<const N: usize, Z = u8>(
 /// Constructor methods.
 impl<Z: PosInt, const N: usize> Bitset<N,Z>
 {
+    /// Evaluated by every constructor to reject a `Z` which cannot hold `N` bits at compile time.
+    const CAPACITY_CHECK: () = assert!(N <= 8 * mem::size_of::<Z>(), "`Z` is too small to hold the `N` bits of this `Bitset`");
+
     /// Construct a set with a single integer `int`.
     /// 
     /// # Panics
@@ -169,13 +177,15 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
     /// ```
     pub fn single(int: impl AnyInt + fmt::Debug) -> Self
     {
+        let () = Self::CAPACITY_CHECK;
+
         let Ok(n) = int.try_into() else {
             panic!("Error constructing a singleton `Bitset`: could not convert `{int:?}` to a `usize`")
         };
 
-        if n < 1 || N < n {
+        let Some(n) = Self::validate(n) else {
             panic!("Error constructing a singleton `Bitset`: received `{int:?}` which is outside of valid range `1..={N}`");
-        }
+        };
 
         let z = Z::one() << (n - 1);
         Bitset(z)
@@ -192,6 +202,7 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
     /// assert_eq!(*off, 0b_0000);
     /// ```
     pub fn none() -> Self {
+        let () = Self::CAPACITY_CHECK;
         Self( Z::zero() )
     }
 
@@ -206,9 +217,296 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
     /// assert_eq!(*off, 0b_1111);
     /// ```
     pub fn all() -> Self {
-        let z = (1 << N) - 1;
-        let z = into_z(z);
-        Self(z)
+        let () = Self::CAPACITY_CHECK;
+        Self::try_all().unwrap()
+    }
+
+    /// Construct a set with all bits enabled, or return a [`CapacityError`] if `Z` has fewer than `N` bits.
+    /// 
+    /// Unlike [`all`](Self::all), this does not reject a `Z` which is too small at compile time, so can be used where `N` and `Z` are not known to fit.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( *Bitset::<4>::try_all().unwrap(), 0b_1111 );
+    /// assert!( Bitset::<9, u8>::try_all().is_err() );
+    /// ```
+    pub fn try_all() -> Result<Self, CapacityError>
+    {
+        let z_bits = bit_width::<Z>();
+
+        if N > z_bits {
+            return Err(CapacityError { n: N, z_bits });
+        }
+
+        Ok(Self(full_bits::<N,Z>()))
+    }
+
+    /// Construct a set with no bits enabled.
+    /// 
+    /// Alias for [`none`](Self::none).
+    pub fn empty() -> Self {
+        Self::none()
+    }
+
+    /// Construct a set with all bits enabled.
+    /// 
+    /// Alias for [`all`](Self::all).
+    pub fn full() -> Self {
+        Self::all()
+    }
+
+    /// Construct a set from a slice of flags, where index `i` indicates whether `i+1` is in the set. Flags beyond index `N-1` are ignored.
+    /// 
+    /// This is the reverse of [`to_vec_bool`](Self::to_vec_bool).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bitset = Bitset::<4>::from_vec_bool(&[true, false, true]);
+    /// assert_eq!(bitset, Bitset::<4>::from([1,3]));
+    /// ```
+    pub fn from_vec_bool(flags: &[bool]) -> Self
+    {
+        let mut out = Self::none();
+
+        for (i, &flag) in flags.iter().take(N).enumerate() {
+            if flag { out.insert_usize(i+1); }
+        }
+
+        out
+    }
+
+    /// Construct a set from `(integer, present)` pairs, inserting each integer whose flag is `true`. Integers outside `1..=N` are ignored.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bitset = Bitset::<4>::from_pairs([(1, true), (2, false), (3, true)]);
+    /// assert_eq!(bitset, Bitset::<4>::from([1,3]));
+    /// ```
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (usize, bool)>) -> Self
+    {
+        let mut out = Self::none();
+
+        for (n, present) in pairs {
+            if present { out.insert_usize(n); }
+        }
+
+        out
+    }
+
+    /// Construct the set `{1, 1+step, 1+2*step, ...}` of integers in `1..=N`. A `step` of `0` gives only `{1}`, and a `step` of `1` gives the full set.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( Bitset::<8>::stride(3), byteset![1,4,7] );
+    /// ```
+    pub fn stride(step: usize) -> Self
+    {
+        let () = Self::CAPACITY_CHECK;
+
+        let mut out = Z::zero();
+
+        for shift in (0..N).step_by(step.max(1)) {
+            out |= Z::one() << shift;
+            if step == 0 { break; }
+        }
+
+        Self(out)
+    }
+
+    /// Construct a set from inclusive ranges `(lower, upper)`, each clamped to `1..=N`. Inverted ranges are ignored.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bitset = Bitset::<9, u16>::from_ranges([(1,3), (7,9)]);
+    /// assert_eq!(bitset, Bitset::<9, u16>::from([1,2,3,7,8,9]));
+    /// ```
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (usize, usize)>) -> Self
+    {
+        let () = Self::CAPACITY_CHECK;
+
+        Self(
+            ranges.into_iter()
+                .fold(Z::zero(), |bits, (lower, upper)| bits | range_bits::<N,Z>(lower, upper))
+        )
+    }
+
+    /// Construct every singleton set `{1}, {2}, ..., {N}`, where index `i` holds the singleton for `i+1`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let singletons = Bitset::<4>::singletons();
+    /// assert_eq!(singletons[0], Bitset::<4>::single(1));
+    /// assert_eq!(singletons[3], Bitset::<4>::single(4));
+    /// ```
+    pub fn singletons() -> [Self; N] {
+        let () = Self::CAPACITY_CHECK;
+        array::from_fn(|i| Self(Z::one() << i))
+    }
+}
+
+/// String conversion methods.
+impl<Z: PosInt, const N: usize> Bitset<N,Z>
+{
+    /// Parse a set from a string of `0`s and `1`s, read as the binary representation of the underlying integer.
+    /// 
+    /// The *rightmost* character corresponds to the integer `1`, exactly as in a `0b` literal. The string may be shorter than `N` characters, but not longer.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns a [`ParseBitsetError`] if the string contains characters other than `0` or `1`, or has more than `N` characters.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bitset = Bitset::<6>::from_binary_str("010110").unwrap();
+    /// assert_eq!(bitset, Bitset::<6>::from([2,3,5]));
+    /// ```
+    pub fn from_binary_str(s: &str) -> Result<Self, ParseBitsetError>
+    {
+        let () = Self::CAPACITY_CHECK;
+
+        let len = s.chars().count();
+
+        if len > N {
+            return Err(ParseBitsetError(
+                format!("binary string `{s}` has {len} digits, but `Bitset` only represents integers `1..={N}`")
+            ));
+        }
+
+        let mut z = Z::zero();
+
+        for c in s.chars() {
+            z <<= Z::one();
+
+            match c {
+                '0' => (),
+                '1' => z |= Z::one(),
+                _ => return Err(ParseBitsetError(
+                    format!("binary string `{s}` contains invalid character `{c}`")
+                )),
+            }
+        }
+
+        Ok(Self(z))
+    }
+
+    /// Parse a set from a string of single digits, such as `"137"` or `"1 3 7"`, where each digit is an integer in the set. Whitespace is ignored.
+    /// 
+    /// This is intended for sets with `N <= 9`, such as Sudoku candidates, where every integer can be written as a single digit.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns a [`ParseBitsetError`] if the string contains a character that is not whitespace or a digit in `1..=N`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bitset = Bitset::<9, u16>::from_digits_str("13 7").unwrap();
+    /// assert_eq!(bitset, Bitset::<9, u16>::from([1,3,7]));
+    /// ```
+    pub fn from_digits_str(s: &str) -> Result<Self, ParseBitsetError>
+    {
+        let mut out = Self::none();
+
+        for c in s.chars().filter(|c| !c.is_whitespace()) {
+            match c.to_digit(10) {
+                Some(d) if 1 <= d && d as usize <= N => out += d,
+                _ => return Err(ParseBitsetError(
+                    format!("digit string `{s}` contains `{c}`, which is not a digit in `1..={}`", N.min(9))
+                )),
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Get the integers in the set in ascending order, joined by `sep`. An empty set gives an empty string.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![1,3,7].join("-"), "1-3-7");
+    /// assert_eq!(byteset![1,3,7].join(", "), "1, 3, 7");
+    /// ```
+    pub fn join(self, sep: &str) -> String
+    {
+        use fmt::Write;
+
+        let mut out = String::new();
+        let mut members = self.iter_asc();
+
+        if let Some(first) = members.next() {
+            let _ = write!(out, "{first}");
+
+            for n in members {
+                out.push_str(sep);
+                let _ = write!(out, "{n}");
+            }
+        }
+
+        out
+    }
+
+    /// Render the set as a bar of `N` characters in ascending order, where each integer in the set is shown as `filled`, and each integer not in the set as `empty`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(Bitset::<4>::from([1,3]).to_ascii_bar('#', '.'), "#.#.");
+    /// ```
+    pub fn to_ascii_bar(self, filled: char, empty: char) -> String
+    {
+        self.flags()
+            .map(|flag| if flag { filled } else { empty })
+            .collect()
+    }
+
+    /// Get the binary representation of the underlying integer as a string of `N` `0`s and `1`s.
+    /// 
+    /// The *rightmost* character corresponds to the integer `1`. This is the reverse of [`from_binary_str`](Self::from_binary_str).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(Bitset::<6>::from([2,3,5]).to_binary_str(), "010110");
+    /// ```
+    pub fn to_binary_str(self) -> String
+    {
+        (1..=N).rev()
+            .map(|n| if self.has_usize(n) { '1' } else { '0' })
+            .collect()
+    }
+}
+
+impl<Z: PosInt, const N: usize> Default for Bitset<N,Z>
+{
+    /// Construct a set with no bits enabled.
+    ///
+    /// Equivalent to [`none`](Self::none), so a `Z` which is too small for `N` is rejected at compile time.
+    ///
+    /// ```rust,compile_fail,E0080
+    /// # use natbitset::*;
+    /// let bitset = Bitset::<9, u8>::default();
+    /// ```
+    fn default() -> Self {
+        Self::none()
     }
 }
 
@@ -235,14 +533,17 @@ impl<Z: PosInt, T: AnyInt, const N: usize> FromIterator<T> for Bitset<N,Z>
     fn from_iter<I>(iter: I) -> Self
         where I: IntoIterator<Item = T>
     {
-        let n = nums::cast::<usize, T>(N).unwrap();
+        let () = Self::CAPACITY_CHECK;
+
+        /* NOTE: if `N` doesn't fit in a `T`, every positive `T` is in range */
+        let n = nums::cast::<usize, T>(N).unwrap_or(T::max_value());
         let zero = T::zero();
 
         Self(
             iter.into_iter()
                 .filter(|t| n >= *t && *t > zero)
-                .map(|t| Z::one() << into_usize(t - T::one()))
-                .sum()
+                .map(|t| into_usize(t - T::one()))
+                .fold(Z::zero(), |bits, shift| bits | Z::one() << shift)
         )
     }
 }
@@ -277,6 +578,24 @@ macro_rules! byteset {
     };
 }
 
+/// Get the [`Bitset<N, Z>`] type with the smallest `Z` that can represent integers `1..=N`, for `N` up to 128.
+/// 
+/// # Usage
+/// 
+/// ```rust
+/// # use natbitset::*;
+/// type Cell = bitset_type!(9);
+/// 
+/// let cell = Cell::all();
+/// let same: Bitset<9, u16> = cell;
+/// ```
+#[macro_export]
+macro_rules! bitset_type {
+    ( $n:expr ) => {
+        $crate::Bitset<{ $n }, <$crate::Capacity<{ $n }> as $crate::Backing>::Z>
+    };
+}
+
 // == TRAITS == //
 impl<Z: PosInt, const N: usize> ops::Deref for Bitset<N,Z> {
     type Target = Z;
@@ -297,82 +616,295 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z> {
     pub fn iter(self) -> BitsetIterator<N,Z> {
         self.into_iter()
     }
-}
-
-impl<Z: PosInt, const N: usize> IntoIterator for Bitset<N,Z> {
-    type Item = usize;
-    type IntoIter = BitsetIterator<N,Z>;
 
     /// Get an iterator over the elements of the set, in descending order.
-    fn into_iter(self) -> Self::IntoIter
-    {
-        BitsetIterator {
-            i: N+1,
-            residue: *self,
-            power_of_2: Z::one() << (N-1),
-        }
+    /// 
+    /// Borrowed form of [`iter`](Self::iter), for when only a reference to the set is available.
+    pub fn iter_ref(&self) -> BitsetIterator<N,Z> {
+        self.into_iter()
     }
-}
-impl<Z: PosInt, const N: usize> IntoIterator for &Bitset<N,Z> {
-    type Item = usize;
-    type IntoIter = BitsetIterator<N,Z>;
 
-    /// Get an iterator over the elements of the set, in descending order.
-    fn into_iter(self) -> Self::IntoIter
+    /// Get an iterator over the elements of the set that are `<= start`, in descending order.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let members: Vec<_> = byteset![1,3,5,8].iter_to(5).collect();
+    /// assert_eq!(members, vec![5,3,1]);
+    /// ```
+    pub fn iter_to<R>(self, start: R) -> BitsetIterator<N,Z>
+        where R: AnyInt
     {
-        BitsetIterator {
-            i: N+1,
-            residue: **self,
-            power_of_2: Z::one() << (N-1),
-        }
+        Self(*self & low_bits(saturate_usize(start))).into_iter()
     }
-}
-
-pub struct BitsetIterator<const N: usize, Z> where Z: PosInt {
-    i: usize,
-    residue: Z,
-    power_of_2: Z,
-}
-impl<Z: PosInt, const N: usize> Iterator for BitsetIterator<N,Z> {
-    type Item = usize;
 
-    fn next(&mut self) -> Option<Self::Item>
-    {
-        let mut out = None;
+    /// Get an iterator over the elements of the set, in ascending order.
+    fn iter_asc(self) -> impl Iterator<Item = usize> {
+        let mut residue = *self.canonical();
 
-        loop {
-            self.i -= 1;
-            if self.i == 0 { return None; }
+        iter::from_fn(move || {
+            if residue == Z::zero() { return None; }
 
-            if self.residue >= self.power_of_2 {
-                self.residue -= self.power_of_2;
-                out = Some(self.i);
-            }
+            let n = residue.trailing_zeros() as usize + 1;
+            residue &= residue - Z::one();
 
-            self.power_of_2 >>= Z::one();
+            Some(n)
+        })
+    }
 
-            if out.is_some() { return out; }
-        }
+    /// Get an iterator over the elements of the set in descending order, each paired with its isolated bit `Z::one() << (member-1)`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let pairs: Vec<_> = byteset![1,3].iter_indexed().collect();
+    /// assert_eq!(pairs, vec![(3, 0b_0100), (1, 0b_0001)]);
+    /// ```
+    pub fn iter_indexed(self) -> impl Iterator<Item = (usize, Z)> {
+        let mut iter = self.into_iter();
+        iter::from_fn(move || iter.next_indexed())
     }
-}
 
-impl<Z: PosInt, const N: usize> PartialOrd for Bitset<N,Z> {
-    /// Checks for a subset relation between `self` and `other`.
+    /// View the set as a collection of bitflags, which can be iterated over to get the bitflag of each element, in descending order.
     /// 
-    /// `self <= other == true` if `self` is a subset of `other`, i.e. all elements of `self` are also elements of `other`.
+    /// # Usage
     /// 
-    /// `self < other == true` if `self` is a *strict* subset of `other`, i.e. `self != other`.
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        let s = *self;
-        let r = *other;
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut flags = vec![];
+    /// 
+    /// for flag in byteset![1,3].as_flags() {
+    ///     flags.push(flag);
+    /// }
+    /// 
+    /// assert_eq!(flags, vec![0b_0100, 0b_0001]);
+    /// ```
+    pub fn as_flags(self) -> AsFlags<N,Z> {
+        AsFlags(self)
+    }
 
-        if s == r {
+    /// Get an iterator over the 0-based bit positions of the elements of the set, in ascending order.
+    /// 
+    /// Each position is 1 less than its element, so this is convenient for indexing into arrays of length `N`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let indices: Vec<usize> = byteset![1,3].bit_indices().collect();
+    /// assert_eq!(indices, vec![0, 2]);
+    /// ```
+    pub fn bit_indices(self) -> impl Iterator<Item = usize> {
+        self.iter_asc().map(|n| n - 1)
+    }
+
+    /// Get the integer represented by the 0-based bit position `bit`, or `None` if `bit` is not in `0..N`.
+    /// 
+    /// This is the reverse of [`bit_of_member`](Self::bit_of_member).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( Bitset::<8>::member_at_bit(0), Some(1) );
+    /// assert_eq!( Bitset::<8>::member_at_bit(8), None );
+    /// ```
+    pub const fn member_at_bit(bit: usize) -> Option<usize> {
+        if bit < N { Some(bit + 1) } else { None }
+    }
+
+    /// Get every integer in `1..=N` as an array `[1, 2, ..., N]`. Can be used in a `const` context.
+    /// 
+    /// This is the allocation-free counterpart of `Bitset::<N,Z>::all().members_asc()`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// const DIGITS: [usize; 9] = Bitset::<9, u16>::universe_array();
+    /// assert_eq!(DIGITS, [1,2,3,4,5,6,7,8,9]);
+    /// ```
+    pub const fn universe_array() -> [usize; N]
+    {
+        let mut out = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            out[i] = i + 1;
+            i += 1;
+        }
+
+        out
+    }
+
+    /// Get the 0-based bit position representing `member`, or `None` if `member` is not in `1..=N`.
+    /// 
+    /// This is the reverse of [`member_at_bit`](Self::member_at_bit).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( Bitset::<8>::bit_of_member(1), Some(0) );
+    /// assert_eq!( Bitset::<8>::bit_of_member(0), None );
+    /// ```
+    pub const fn bit_of_member(member: usize) -> Option<usize> {
+        if 1 <= member && member <= N { Some(member - 1) } else { None }
+    }
+
+    /// Get an iterator over whether each integer in `1..=N` is in the set, in ascending order.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let flags: Vec<bool> = Bitset::<4>::from([1,3]).flags().collect();
+    /// assert_eq!(flags, vec![true, false, true, false]);
+    /// ```
+    pub fn flags(self) -> impl Iterator<Item = bool> {
+        (1..=N).map(move |n| self.has_usize(n))
+    }
+}
+
+impl<Z: PosInt, const N: usize> IntoIterator for Bitset<N,Z> {
+    type Item = usize;
+    type IntoIter = BitsetIterator<N,Z>;
+
+    /// Get an iterator over the elements of the set, in descending order.
+    fn into_iter(self) -> Self::IntoIter
+    {
+        BitsetIterator {
+            i: N+1,
+            residue: *self.canonical(),
+            power_of_2: Z::one() << (N-1),
+        }
+    }
+}
+impl<Z: PosInt, const N: usize> IntoIterator for &Bitset<N,Z> {
+    type Item = usize;
+    type IntoIter = BitsetIterator<N,Z>;
+
+    /// Get an iterator over the elements of the set, in descending order.
+    fn into_iter(self) -> Self::IntoIter
+    {
+        BitsetIterator {
+            i: N+1,
+            residue: *self.canonical(),
+            power_of_2: Z::one() << (N-1),
+        }
+    }
+}
+
+pub struct BitsetIterator<const N: usize, Z> where Z: PosInt {
+    i: usize,
+    residue: Z,
+    power_of_2: Z,
+}
+impl<Z: PosInt, const N: usize> BitsetIterator<N,Z> {
+    /// Step to the next member, returning it alongside its isolated bit.
+    fn next_indexed(&mut self) -> Option<(usize, Z)>
+    {
+        let mut out = None;
+
+        loop {
+            /* NOTE: guard before decrementing, so the iterator stays exhausted once it returns `None` */
+            if self.i <= 1 {
+                self.i = 0;
+                return None;
+            }
+            self.i -= 1;
+
+            if self.residue >= self.power_of_2 {
+                self.residue -= self.power_of_2;
+                out = Some((self.i, self.power_of_2));
+            }
+
+            self.power_of_2 >>= Z::one();
+
+            if out.is_some() { return out; }
+        }
+    }
+}
+impl<Z: PosInt, const N: usize> Iterator for BitsetIterator<N,Z> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.next_indexed().map(|(n, _)| n)
+    }
+}
+
+impl<Z: PosInt, R: AnyInt, const N: usize> Extend<R> for Bitset<N,Z> {
+    /// Add each integer from `iter` to the set, ignoring those not in the range `1..=N`.
+    fn extend<I>(&mut self, iter: I)
+        where I: IntoIterator<Item = R>
+    {
+        for int in iter {
+            *self += int;
+        }
+    }
+}
+
+impl<Z: PosInt, const N: usize> Extend<Bitset<N,Z>> for Bitset<N,Z> {
+    /// Union each set from `iter` into `self`.
+    fn extend<I>(&mut self, iter: I)
+        where I: IntoIterator<Item = Bitset<N,Z>>
+    {
+        for other in iter {
+            *self |= other;
+        }
+    }
+}
+
+impl<Z: PosInt, const N: usize> iter::FusedIterator for BitsetIterator<N,Z> {}
+
+/// A view of a [`Bitset`] as a collection of bitflags, obtained through [`Bitset::as_flags`].
+/// 
+/// Iterating over it yields the bitflag of each element in the set, i.e. `Z::one() << (element - 1)`, in descending order.
+#[derive(Copy, Clone, Debug)]
+pub struct AsFlags<const N: usize, Z>(pub Bitset<N,Z>) where Z: PosInt;
+
+impl<Z: PosInt, const N: usize> IntoIterator for AsFlags<N,Z> {
+    type Item = Z;
+    type IntoIter = FlagsIterator<N,Z>;
+
+    /// Get an iterator over the bitflags of the elements of the set, in descending order.
+    fn into_iter(self) -> Self::IntoIter {
+        FlagsIterator(self.0.into_iter())
+    }
+}
+
+pub struct FlagsIterator<const N: usize, Z>(BitsetIterator<N,Z>) where Z: PosInt;
+
+impl<Z: PosInt, const N: usize> Iterator for FlagsIterator<N,Z> {
+    type Item = Z;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_indexed().map(|(_, bit)| bit)
+    }
+}
+
+impl<Z: PosInt, const N: usize> iter::FusedIterator for FlagsIterator<N,Z> {}
+
+impl<Z: PosInt, const N: usize> PartialOrd for Bitset<N,Z> {
+    /// Checks for a subset relation between `self` and `other`.
+    /// 
+    /// `self <= other == true` if `self` is a subset of `other`, i.e. all elements of `self` are also elements of `other`.
+    /// 
+    /// `self < other == true` if `self` is a *strict* subset of `other`, i.e. `self != other`.
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        let s = *self;
+        let r = *other;
+
+        if s == r {
             return Some(cmp::Ordering::Equal)
         }
-        else if *(s / r) == Z::zero() {
+        else if (s / r).is_empty() {
             return Some(cmp::Ordering::Less)
         }
-        else if *(r / s) == Z::zero() {
+        else if (r / s).is_empty() {
             return Some(cmp::Ordering::Greater)
         }
         
@@ -380,6 +912,22 @@ impl<Z: PosInt, const N: usize> PartialOrd for Bitset<N,Z> {
     }
 }
 
+/// Equality compares the [canonical](Bitset::canonical) forms of two sets, so any bits above `N` are ignored.
+impl<Z: PosInt, const N: usize> PartialEq for Bitset<N,Z> {
+    fn eq(&self, other: &Self) -> bool {
+        self.set_eq(other)
+    }
+}
+
+impl<Z: PosInt, const N: usize> Eq for Bitset<N,Z> {}
+
+/// Hashing uses the [canonical](Bitset::canonical) form of a set, so sets which compare equal always hash equally.
+impl<Z: PosInt + hash::Hash, const N: usize> hash::Hash for Bitset<N,Z> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.canonical().0.hash(state);
+    }
+}
+
 impl<Z: PosInt, const N: usize> fmt::Debug for Bitset<N,Z> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Bitset {{")?;
@@ -404,13 +952,15 @@ impl<Z: PosInt, const N: usize> ops::BitOr for Bitset<N,Z> {
 
     /// Return the union of `self` and `other`, i.e. the combined integers of both sets.
     fn bitor(self, other: Self) -> Self {
+        if *other == Z::zero() { return self; }
+
         Bitset(*self | *other)
     }
 }
 impl<Z: PosInt, const N: usize> ops::BitOrAssign for Bitset<N,Z> {
     /// Union `self` with `other`.
     fn bitor_assign(&mut self, other: Self) {
-        **self |= *other;
+        *self = *self | other;
     }
 }
 
@@ -419,13 +969,16 @@ impl<Z: PosInt, const N: usize> ops::BitAnd for Bitset<N,Z> {
 
     /// Return the intersection of `self` and `other`, i.e. the integers that are members of both sets.
     fn bitand(self, other: Self) -> Self {
+        if *other == Z::zero() { return Self::none(); }
+        if *other == full_bits::<N,Z>() { return self.canonical(); }
+
         Bitset(*self & *other)
     }
 }
 impl<Z: PosInt, const N: usize> ops::BitAndAssign for Bitset<N,Z> {
     /// Intersect `self` with `other`.
     fn bitand_assign(&mut self, other: Self) {
-        **self &= *other;
+        *self = *self & other;
     }
 }
 
@@ -434,14 +987,15 @@ impl<Z: PosInt, const N: usize> ops::Div for Bitset<N,Z> {
 
     /// Return the difference of `self` and `other`, i.e. the integers that are members of `self` but not `other`.
     fn div(self, other: Self) -> Self::Output {
+        if *other == Z::zero() { return self; }
+
         Bitset(*self - (*self & *other))
     }
 }
 impl<Z: PosInt, const N: usize> ops::DivAssign for Bitset<N,Z> {
     /// Remove the elements of `other` from `self`.
     fn div_assign(&mut self, other: Self) {
-        let intersect = **self & *other;
-        **self -= intersect;
+        *self = *self / other;
     }
 }
 
@@ -453,6 +1007,12 @@ impl<Z: PosInt, const N: usize> ops::BitXor for Bitset<N,Z> {
         (self | other) / (self & other)
     }
 }
+impl<Z: PosInt, const N: usize> ops::BitXorAssign for Bitset<N,Z> {
+    /// Toggle the elements of `other` in `self`.
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = *self ^ other;
+    }
+}
 
 impl<Z: PosInt, R: AnyInt, const N: usize> ops::Add<R> for Bitset<N,Z>
 {
@@ -463,9 +1023,7 @@ impl<Z: PosInt, R: AnyInt, const N: usize> ops::Add<R> for Bitset<N,Z>
     /// If you wish to be notified when an insertion fails, use [`insert`](Self::insert) or [`try_insert`](Self::try_insert) (but note these are out-of-place).
     fn add(self, int: R) -> Self
     {
-        if let Ok(int) = int.try_into()
-        && N >= int
-        {
+        if let Some(int) = Self::validate(int) {
             let bit = Z::one() << (int - 1);
             Bitset(*self | bit)
         }
@@ -495,9 +1053,7 @@ impl<Z: PosInt, R: AnyInt, const N: usize> ops::Sub<R> for Bitset<N,Z>
     /// If you wish to be notified when a removal leaves the set empty, use [`remove_nonempty`](Self::remove_nonempty) or [`remove_nonempty_panicking`](Self::remove_nonempty_panicking).
     fn sub(self, int: R) -> Self
     {
-        if let Ok(int) = int.try_into()
-        && N >= int
-        {
+        if let Some(int) = Self::validate(int) {
             let bit = Z::one() << (int - 1);
             let intersect = *self & bit;
             Bitset(*self - intersect)
@@ -522,22 +1078,33 @@ impl<Z: PosInt, R: AnyInt, const N: usize> ops::SubAssign<R> for Bitset<N,Z>
 impl<Z: PosInt, const N: usize> Bitset<N,Z>
 {
     /// How many integers are in the set?
-    pub fn len(self) -> usize
-    {
-        let mut out = 0;
-        let mut residue = *self;
-        let mut power_of_2 = Z::one() << (N-1);
+    pub fn len(self) -> usize {
+        self.canonical().count_ones() as usize
+    }
 
-        for _ in (0..N).rev() {
-            if residue >= power_of_2 {
-                residue -= power_of_2;
-                out += 1;
-            }
-            
-            power_of_2 >>= Z::one();
-        }
+    /// Does the set contain an odd number of integers?
+    pub fn len_is_odd(self) -> bool {
+        self.len() % 2 == 1
+    }
 
-        out
+    /// Does the set contain at least `k` integers?
+    pub fn len_at_least(self, k: usize) -> bool {
+        self.len() >= k
+    }
+
+    /// Does the set contain at most `k` integers?
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let cells = [byteset![1,2], byteset![3], byteset![4,5,6]];
+    /// let pairs = cells.iter().filter(|cell| cell.len_at_most(2) && cell.len_at_least(2));
+    /// 
+    /// assert_eq!( pairs.count(), 1 );
+    /// ```
+    pub fn len_at_most(self, k: usize) -> bool {
+        self.len() <= k
     }
 
     /// Does the set contain `int`?
@@ -546,12 +1113,25 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
     pub fn contains<R>(self, int: &R) -> bool
         where R: AnyInt
     {
-        if let Ok(val) = (*int).try_into() {
-            self.iter().any(|n| n == val)
-        }
-        else {
-            false
-        }
+        self.has(*int)
+    }
+
+    /// Check whether `int` can be in the set, returning it as a `usize` in a `Some()` if it is in `1..=N` and can be represented by `Z`, or `None` otherwise.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( Bitset::<8>::validate(3), Some(3) );
+    /// assert_eq!( Bitset::<8>::validate(0), None );
+    /// assert_eq!( Bitset::<8>::validate(-3), None );
+    /// assert_eq!( Bitset::<16, u16>::validate(17), None );
+    /// ```
+    pub fn validate<R>(int: R) -> Option<usize>
+        where R: AnyInt
+    {
+        let n = int.try_into().ok()?;
+        (1 <= n && n <= N && n <= bit_width::<Z>()).then_some(n)
     }
 
     /// Add `int` to the set. Returns whether the integer was newly inserted.
@@ -564,6 +1144,19 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         *self != before
     }
 
+    /// Add `n` to the set. Returns whether the integer was newly inserted.
+    /// 
+    /// Non-generic form of [`insert`](Self::insert), for when the integer is already a `usize`.
+    pub fn insert_usize(&mut self, n: usize) -> bool
+    {
+        let Some(n) = Self::validate(n) else { return false };
+
+        let before = *self;
+        **self |= Z::one() << (n - 1);
+
+        *self != before
+    }
+
     /// Try add `int` to the set by casting it into `usize`. Returns an `Ok` indicating whether the integer was newly inserted, or an `Err` if casting failed.
     pub fn try_insert<R>(&mut self, int: R) -> Result<bool, R::Error>
         where R: AnyInt
@@ -572,7 +1165,7 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
 
         let before = *self;
 
-        if N >= n {
+        if let Some(n) = Self::validate(n) {
             let bit = Z::one() << (n - 1);
             **self |= bit
         }
@@ -599,7 +1192,7 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         let before = *self;
         let bits_before = *before;
 
-        if N >= n {
+        if let Some(n) = Self::validate(n) {
             let bit = Z::one() << (n - 1);
             let intersect = bits_before & bit;
             **self = bits_before - intersect;
@@ -608,11 +1201,51 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         Ok(*self != before)
     }
 
+    /// Remove each integer from `iter` from the set. Returns how many of the integers were members of the set.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1,2,3,4];
+    /// assert_eq!(bitset.remove_iter([2,4,99]), 2);
+    /// assert_eq!(bitset, byteset![1,3]);
+    /// ```
+    pub fn remove_iter<R>(&mut self, iter: impl IntoIterator<Item = R>) -> usize
+        where R: AnyInt
+    {
+        let before = self.count_ones();
+
+        for int in iter {
+            *self -= int;
+        }
+
+        (before - self.count_ones()) as usize
+    }
+
+    /// Remove each integer from `iter` from the set.
+    /// 
+    /// Same as [`remove_iter`](Self::remove_iter), but without counting the removed integers.
+    pub fn difference_update_iter<R>(&mut self, iter: impl IntoIterator<Item = R>)
+        where R: AnyInt
+    {
+        self.remove_iter(iter);
+    }
+
     /// Clear the set, removing all integers.
     pub fn clear(&mut self) {
         **self = Z::zero();
     }
 
+    /// Clear the set only if `cond` is `true`. Returns whether the set was changed, i.e. `cond` was `true` and the set was non-empty.
+    pub fn clear_if(&mut self, cond: bool) -> bool
+    {
+        let changed = cond && !self.is_empty();
+        if changed { self.clear(); }
+
+        changed
+    }
+
     /// Return the union of `self` and `other`, i.e. the combined integers of both sets.
     pub fn union(self, other: &Self) -> Self {
         self | *other
@@ -633,9 +1266,45 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         self ^ *other
     }
 
+    /// Split the union of `self` and `other` into the elements only in `self`, the elements in both, and the elements only in `other`.
+    /// 
+    /// The three sets are pairwise disjoint, and their union is `self | other`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let (left, both, right) = byteset![1,2,3].union_tagged(&byteset![3,4]);
+    /// 
+    /// assert_eq!(left, byteset![1,2]);
+    /// assert_eq!(both, byteset![3]);
+    /// assert_eq!(right, byteset![4]);
+    /// ```
+    pub fn union_tagged(self, other: &Self) -> (Self, Self, Self)
+    {
+        let both = self & *other;
+
+        (self ^ both, both, *other ^ both)
+    }
+
+    /// Count the elements `self` and `other` have in common, i.e. the size of their intersection.
+    /// 
+    /// This is a single bitwise AND and popcount, so prefer it over `(self & other).len()` in hot loops.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,2,4].common_count(&byteset![2,3,4]), 2 );
+    /// ```
+    #[inline]
+    pub fn common_count(self, other: &Self) -> usize {
+        (*self & **other & full_bits::<N,Z>()).count_ones() as usize
+    }
+
     /// Do `self` and `other` have no elements in common? (i.e. is the intersection empty?)
     pub fn is_disjoint(self, other: &Self) -> bool {
-        *(self & *other) == Z::zero()
+        (self & *other).is_empty()
     }
 
     /// Is `self` a subset of `other`?
@@ -652,11 +1321,28 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         self >= *other
     }
 
+    /// Is the difference `self / remove` a subset of `target`?
+    /// 
+    /// Equivalent to `(self / *remove).is_subset(target)`, but without constructing any intermediate sets.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert!( byteset![1,2,3].difference_subset_of(&byteset![3], &byteset![1,2]) );
+    /// assert!( !byteset![1,2,3].difference_subset_of(&byteset![1], &byteset![1,2]) );
+    /// ```
+    pub fn difference_subset_of(self, remove: &Self, target: &Self) -> bool {
+        *self & !(**remove | **target) & full_bits::<N,Z>() == Z::zero()
+    }
+
     /// (in-place) Filter `self` to keep only elements that fulfil `predicate`, i.e. remove elements for which `predicate(element) == false`.
+    /// 
+    /// Elements are visited in *descending* order. If `predicate` relies on the order of visitation, use [`retain_asc`](Self::retain_asc) to visit them in ascending order.
     pub fn retain(&mut self, mut predicate: impl FnMut(usize) -> bool)
     {
         let mut res = Z::zero();
-        let mut residue = **self;
+        let mut residue = *self.canonical();
         let mut power_of_2 = Z::one() << (N-1);
 
         for i in (1..=N).rev() {
@@ -673,16 +1359,111 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
 
         **self = res;
     }
-}
-
-// == QUERY METHODS == //
-/// Specialised methods for querying the set.
-impl<Z: PosInt, const N: usize> Bitset<N,Z>
-{
-    /// Is the set empty?
-    pub fn is_empty(self) -> bool {
-        *self == Z::zero()
-    }
+
+    /// (in-place) Filter `self` to keep only elements that fulfil `predicate`, visiting elements in *ascending* order.
+    /// 
+    /// See [`retain`](Self::retain) for more info.
+    pub fn retain_asc(&mut self, mut predicate: impl FnMut(usize) -> bool)
+    {
+        let mut res = Z::zero();
+        let mut power_of_2 = Z::one();
+
+        for i in 1..=N {
+            if **self & power_of_2 != Z::zero() && predicate(i) {
+                res |= power_of_2;
+            }
+
+            if i < N { power_of_2 <<= Z::one(); }
+        }
+
+        **self = res;
+    }
+
+    /// (in-place) Filter `self` to keep only elements for which `predicate` returns `Ok(true)`, visiting elements in descending order.
+    /// 
+    /// If `predicate` returns an `Err`, stop and return it, leaving `self` unchanged.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1,2,3,4];
+    /// 
+    /// assert_eq!( bitset.try_retain(|n| if n < 4 { Ok(n != 2) } else { Err(n) }), Err(4) );
+    /// assert_eq!( bitset, byteset![1,2,3,4] );
+    /// 
+    /// assert_eq!( bitset.try_retain(|n| Ok::<_, ()>(n != 2)), Ok(()) );
+    /// assert_eq!( bitset, byteset![1,3,4] );
+    /// ```
+    pub fn try_retain<E>(&mut self, mut predicate: impl FnMut(usize) -> Result<bool, E>) -> Result<(), E>
+    {
+        let mut res = *self;
+
+        for n in self.iter() {
+            if !predicate(n)? {
+                res -= n;
+            }
+        }
+
+        *self = res;
+
+        Ok(())
+    }
+
+    /// (in-place) Remove elements that fulfil `predicate` from `self`, returning them as a new set.
+    /// 
+    /// This is the opposite of [`retain`](Self::retain), and visits elements in the same (descending) order.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1;6];
+    /// let evens = bitset.drain_filter(|n| n % 2 == 0);
+    /// 
+    /// assert_eq!(evens, byteset![2,4,6]);
+    /// assert_eq!(bitset, byteset![1,3,5]);
+    /// ```
+    pub fn drain_filter(&mut self, mut predicate: impl FnMut(usize) -> bool) -> Self
+    {
+        let before = *self;
+        self.retain(|n| !predicate(n));
+
+        before / *self
+    }
+}
+
+// == QUERY METHODS == //
+/// Specialised methods for querying the set.
+impl<Z: PosInt, const N: usize> Bitset<N,Z>
+{
+    /// Is the set empty?
+    pub fn is_empty(self) -> bool {
+        *self.canonical() == Z::zero()
+    }
+
+    /// What fraction of the integers in `1..=N` are in the set? This ranges from `0.0` for an empty set to `1.0` for a full set.
+    pub fn fill_fraction(self) -> f64 {
+        self.len() as f64 / N as f64
+    }
+
+    /// How many integers in `1..=N` are not in the set?
+    pub fn remaining_capacity(self) -> usize {
+        N - self.len()
+    }
+
+    /// Get a [`BitsetSummary`] of the integers present in and absent from the set, its size, and its density.
+    pub fn summary(self) -> BitsetSummary<N,Z>
+    {
+        let present = self.canonical();
+
+        BitsetSummary {
+            present,
+            absent: Self(full_bits::<N,Z>() & !*present),
+            len: present.len(),
+            density: present.fill_fraction(),
+        }
+    }
 
     /// Does the set contain only 1 integer?
     pub fn is_single(self) -> bool {
@@ -694,117 +1475,1097 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         self == Self::all()
     }
 
-    /// Does the set contain `int`?
+    /// Does the set contain `int`?
+    /// 
+    /// Non-borrowed form of [`contains`](Self::contains).
+    pub fn has<R>(self, int: R) -> bool
+        where R: AnyInt
+    {
+        int.try_into().is_ok_and(|n| self.has_usize(n))
+    }
+
+    /// Does the set contain `n`?
+    /// 
+    /// Non-generic form of [`has`](Self::has), for when the integer is already a `usize`.
+    pub fn has_usize(self, n: usize) -> bool
+    {
+        Self::validate(n).is_some_and(|n| *self & (Z::one() << (n - 1)) != Z::zero())
+    }
+
+    /// Check which elements of `queries` are in the set. Returns the elements that are present and absent, respectively.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let (present, absent) = byteset![1,2,3].check(&byteset![3,4]);
+    /// assert_eq!(present, byteset![3]);
+    /// assert_eq!(absent, byteset![4]);
+    /// ```
+    pub fn check(self, queries: &Self) -> (Self, Self) {
+        (self & *queries, *queries / self)
+    }
+
+    /// Compare `self` with a later state `after`. Returns the elements that were added and removed, respectively.
+    /// 
+    /// Removing the removed elements from `self` and then adding the added elements reproduces `after`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let before = byteset![1,2,3];
+    /// let after = byteset![2,3,4];
+    /// 
+    /// let (added, removed) = before.diff(&after);
+    /// assert_eq!(added, byteset![4]);
+    /// assert_eq!(removed, byteset![1]);
+    /// assert_eq!((before / removed) | added, after);
+    /// ```
+    pub fn diff(self, after: &Self) -> (Self, Self) {
+        (*after / self, self / *after)
+    }
+
+    /// Intersect `self` with each set from `iter` in turn, stopping as soon as the intersection becomes empty.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let sets = [byteset![1,2,3], byteset![2,3,4], byteset![3,4,5]];
+    /// assert_eq!(byteset![1;8].intersect_iter(sets), byteset![3]);
+    /// ```
+    pub fn intersect_iter(self, iter: impl IntoIterator<Item = Self>) -> Self
+    {
+        let mut out = self;
+        if out.is_empty() { return Self::none(); }
+
+        for other in iter {
+            out &= other;
+            if out.is_empty() { return Self::none(); }
+        }
+
+        out
+    }
+
+    /// Combine `self` and `other` integer by integer, where each integer in `1..=N` is in the output if `f(self.has(i), other.has(i))` returns `true`.
+    /// 
+    /// This generalises the other set operations, e.g. `combine(other, |a, b| a || b)` is the union of `self` and `other`. Prefer using those operations where possible, since they operate on all the bits at once.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let left = byteset![1,2];
+    /// let right = byteset![2,3];
+    /// 
+    /// assert_eq!(left.combine(right, |a, b| a || b), left | right);
+    /// assert_eq!(left.combine(right, |a, b| !a && !b), byteset![4;8]);
+    /// ```
+    pub fn combine(self, other: Self, mut f: impl FnMut(bool, bool) -> bool) -> Self
+    {
+        let mut out = Self::none();
+
+        for i in 1..=N {
+            if f(self.has_usize(i), other.has_usize(i)) {
+                out.insert_usize(i);
+            }
+        }
+
+        out
+    }
+
+    /// Get the number of integers in `1..=N` that are in exactly one of `self` and `other`.
+    /// 
+    /// This is the size of the [`symmetric_difference`](Self::symmetric_difference), computed directly from the bits.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![1,2,3].hamming_distance(&byteset![3,4]), 3);
+    /// ```
+    pub fn hamming_distance(self, other: &Self) -> usize {
+        ((*self ^ **other) & full_bits::<N,Z>()).count_ones() as usize
+    }
+
+    /// Can `self` and `other` be combined under a limit of `capacity` elements? This requires both:
+    /// 
+    /// - `self` and `other` are disjoint, i.e. they have no elements in common.
+    /// - `self` and `other` have at most `capacity` elements in total.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert!(  byteset![1,2].fits_with(&byteset![3], 3) );
+    /// assert!( !byteset![1,2].fits_with(&byteset![3], 2) );
+    /// assert!( !byteset![1,2].fits_with(&byteset![2], 3) );
+    /// ```
+    pub fn fits_with(self, other: &Self, capacity: usize) -> bool {
+        self.is_disjoint(other)
+            && self.len() + other.len() <= capacity
+    }
+
+    /// Get the canonical form of the set, with any bits above `N` cleared.
+    /// 
+    /// `Eq` and `Hash` both operate on this form, so `a.canonical() == b.canonical()` guarantees `a` and `b` hash equally. This makes a `Bitset` safe to use as a `HashMap` key even if its underlying integer has been dirtied.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let dirty = Bitset::<4>(0b_1000_0101);
+    /// 
+    /// assert_eq!( *dirty.canonical(), 0b_0000_0101 );
+    /// assert_eq!( dirty.canonical(), dirty );
+    /// ```
+    pub fn canonical(self) -> Self {
+        Self(*self & full_bits::<N,Z>())
+    }
+
+    /// Do `self` and `other` contain the same integers in `1..=N`?
+    /// 
+    /// This ignores any bits above `N`, and is equivalent to `self == other`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let clean = Bitset::<4>(0b_0000_0101);
+    /// let dirty = Bitset::<4>(0b_1000_0101);
+    /// 
+    /// assert!(*clean != *dirty);
+    /// assert!(clean.set_eq(&dirty));
+    /// ```
+    pub fn set_eq(self, other: &Self) -> bool {
+        (*self ^ **other) & full_bits::<N,Z>() == Z::zero()
+    }
+
+    /// Are `self` and `other` equal after removing the elements of `ignore` from both?
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert!(  byteset![1,2,3].eq_ignoring(&byteset![1,2,4], &byteset![3,4]) );
+    /// assert!( !byteset![1,2,3].eq_ignoring(&byteset![1,2,4], &byteset![3]) );
+    /// ```
+    pub fn eq_ignoring(self, other: &Self, ignore: &Self) -> bool {
+        (self / *ignore) == (*other / *ignore)
+    }
+
+    /// Get an iterator over pairs of elements from `self` and `other`, paired by *rank* (not by value). The `i`-th pair consists of the `i`-th smallest element of each set.
+    /// 
+    /// The iterator stops once either set runs out of elements.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let pairs: Vec<_> = byteset![1,3,5].zip_members(byteset![2,4]).collect();
+    /// assert_eq!(pairs, vec![(1,2), (3,4)]);
+    /// ```
+    pub fn zip_members(self, other: Self) -> impl Iterator<Item = (usize, usize)> {
+        self.iter_asc().zip(other.iter_asc())
+    }
+
+    /// Split the set into its odd and even integers, respectively.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![1,2,3,4].by_parity(), (byteset![1,3], byteset![2,4]));
+    /// ```
+    pub fn by_parity(self) -> (Self, Self)
+    {
+        /* NOTE: 0b...0101, since odd integers are represented by even bits */
+        let odds = Z::max_value() / (Z::one() + Z::one() + Z::one());
+        let bits = *self.canonical();
+
+        (Self(bits & odds), Self(bits & !odds))
+    }
+
+    /// Get the elements of the set that lie within `range`, which is clamped to `1..=N`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bitset = byteset![1,3,5,7];
+    /// 
+    /// assert_eq!(bitset.slice(..=5),  byteset![1,3,5]);
+    /// assert_eq!(bitset.slice(2..),   byteset![3,5,7]);
+    /// assert_eq!(bitset.slice(2..5),  byteset![3]);
+    /// ```
+    pub fn slice(self, range: impl ops::RangeBounds<usize>) -> Self
+    {
+        let lower = match range.start_bound() {
+            ops::Bound::Included(&n) => n,
+            ops::Bound::Excluded(&n) => n.saturating_add(1),
+            ops::Bound::Unbounded    => 1,
+        }.max(1);
+
+        let upper = match range.end_bound() {
+            ops::Bound::Included(&n) => n,
+            ops::Bound::Excluded(&n) => n.saturating_sub(1),
+            ops::Bound::Unbounded    => N,
+        }.min(N);
+
+        Self(*self & range_bits::<N,Z>(lower, upper))
+    }
+
+    /// Shift every integer in the set up by `by`, discarding those that exceed `N`.
+    /// 
+    /// Unlike `*bitset << by`, this never overflows, and returns an empty set if `by >= N`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,4,6].shift_left(2), byteset![3,6,8] );
+    /// assert_eq!( byteset![1,4,6].shift_left(8), byteset![] );
+    /// ```
+    pub fn shift_left(self, by: usize) -> Self
+    {
+        if by >= N.min(bit_width::<Z>()) { return Self::none(); }
+
+        Self((*self << by) & full_bits::<N,Z>())
+    }
+
+    /// Shift every integer in the set down by `by`, discarding those that fall below 1.
+    /// 
+    /// Unlike `*bitset >> by`, this never overflows, and returns an empty set if `by >= N`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,4,6].shift_right(2), byteset![2,4] );
+    /// assert_eq!( byteset![1,4,6].shift_right(8), byteset![] );
+    /// ```
+    pub fn shift_right(self, by: usize) -> Self
+    {
+        if by >= N.min(bit_width::<Z>()) { return Self::none(); }
+
+        Self((*self & full_bits::<N,Z>()) >> by)
+    }
+
+    /// Rotate every integer in the set up by `by`, treating `1..=N` as a ring so that integers exceeding `N` wrap around to 1.
+    /// 
+    /// Hence an integer `m` maps to `(m - 1 + by) % N + 1`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,8].rotate_left(1), byteset![1,2] );
+    /// assert_eq!( byteset![1,8].rotate_left(10), byteset![2,3] );
+    /// ```
+    pub fn rotate_left(self, by: usize) -> Self
+    {
+        let bits = *self & full_bits::<N,Z>();
+
+        let by = by % N;
+        if by == 0 { return Self(bits); }
+
+        let out = self.shift_left(by) | self.shift_right(N - by);
+        debug_assert_eq!(out.count_ones(), bits.count_ones(), "rotating `Bitset` changed its size");
+
+        out
+    }
+
+    /// Rotate every integer in the set down by `by`, treating `1..=N` as a ring so that integers falling below 1 wrap around to `N`.
+    /// 
+    /// This is the reverse of [`rotate_left`](Self::rotate_left).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,8].rotate_right(1), byteset![7,8] );
+    /// ```
+    pub fn rotate_right(self, by: usize) -> Self {
+        self.rotate_left(N - by % N)
+    }
+
+    /// Rotate every integer in the set by `by`, treating `1..=N` as a ring. A positive `by` rotates up as with [`rotate_left`](Self::rotate_left), while a negative `by` rotates down as with [`rotate_right`](Self::rotate_right).
+    /// 
+    /// Rotating never changes the size of the set.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,8].rotate(1),  byteset![1,2] );
+    /// assert_eq!( byteset![1,8].rotate(-1), byteset![7,8] );
+    /// ```
+    pub fn rotate(self, by: isize) -> Self
+    {
+        if by >= 0 { self.rotate_left(by.unsigned_abs()) }
+        else { self.rotate_right(by.unsigned_abs()) }
+    }
+
+    /// Reverse the set within `1..=N`, so that each integer `n` maps to `N + 1 - n`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,2,6].reversed(), byteset![3,7,8] );
+    /// ```
+    pub fn reversed(self) -> Self
+    {
+        let bits = (*self & full_bits::<N,Z>()).reverse_bits();
+        let excess = bit_width::<Z>() - N.min(bit_width::<Z>());
+
+        Self(bits >> excess)
+    }
+
+    /// Get the integers in the set.
+    /// 
+    /// If you only need to iterate over the integers lazily, prefer using [`.iter()`](Self::iter).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// use std::collections::HashSet;
+    /// 
+    /// let bitset = byteset![1,3,7];
+    /// let nums = bitset.members();
+    /// 
+    /// assert_eq!(nums, HashSet::from([1,3,7]));
+    /// ```
+    pub fn members(self) -> HashSet<usize>
+    {
+        let mut out = HashSet::new();
+        let mut residue = *self.canonical();
+        let mut power_of_2 = Z::one() << (N-1);
+
+        for i in (1..=N).rev() {
+            if residue >= power_of_2 {
+                residue -= power_of_2;
+                out.insert(i);
+            }
+            
+            power_of_2 >>= Z::one();
+        }
+
+        out
+    }
+
+    /// Get the integers in the set, sorted in ascending order.
+    /// 
+    /// # Notes
+    /// 
+    /// - This is slightly more expensive than [`members_desc`](Self::members_desc) since it requires reversing the output of [`members_desc`](Self::members_desc).
+    ///   - This is a limitation of how the integers present in the set are determined from the bitflags.
+    pub fn members_asc(self) -> Vec<usize>
+    {
+        let mut out = self.members_desc();
+        out.reverse();
+        out
+    }
+
+    /// Get the integers in the set, sorted in descending order.
+    pub fn members_desc(self) -> Vec<usize>
+    {
+        self.into_iter().collect::<Vec<usize>>()
+    }
+
+    /// Count how many integers in the set fulfil `predicate`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![1,2,3,4].count_matching(|n| n % 2 == 0), 2);
+    /// ```
+    pub fn count_matching(self, mut predicate: impl FnMut(usize) -> bool) -> usize {
+        self.iter().filter(|&n| predicate(n)).count()
+    }
+
+    /// Fold the integers in the set into an accumulator using `f`, in descending order, stopping at the first `Err`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let product = byteset![2,3,4].try_fold_members(1u8, |acc, n| acc.checked_mul(n as u8).ok_or(n));
+    /// assert_eq!(product, Ok(24));
+    /// 
+    /// let product = byteset![2,3,4].try_fold_members(1u8, |acc, n| if n == 3 { Err(n) } else { Ok(acc * n as u8) });
+    /// assert_eq!(product, Err(3));
+    /// ```
+    pub fn try_fold_members<B, E>(self, init: B, f: impl FnMut(B, usize) -> Result<B, E>) -> Result<B, E> {
+        self.iter().try_fold(init, f)
+    }
+
+    /// Get the smallest integer in the set that fulfils `predicate`, or `None` if there is none.
+    /// 
+    /// Elements are visited in ascending order, stopping at the first match.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![1,4,6].find_member(|n| n % 2 == 0), Some(4) );
+    /// assert_eq!( byteset![1,3,5].find_member(|n| n % 2 == 0), None );
+    /// ```
+    pub fn find_member(self, mut predicate: impl FnMut(usize) -> bool) -> Option<usize> {
+        self.iter_asc().find(|&n| predicate(n))
+    }
+
+    /// Apply `f` to the integers in the set in ascending order, returning the first `Some` result, or `None` if there is none.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let halves = byteset![1,4,6].find_map_member(|n| (n % 2 == 0).then_some(n / 2));
+    /// assert_eq!(halves, Some(2));
+    /// ```
+    pub fn find_map_member<T>(self, f: impl FnMut(usize) -> Option<T>) -> Option<T> {
+        self.iter_asc().find_map(f)
+    }
+
+    /// Count how many of `sets` have each size. Returns a `Vec` of length `N + 1`, where index `k` is the number of sets with `k` elements.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let sets = [byteset![1], byteset![2], byteset![1,2,3]];
+    /// assert_eq!(Bitset::cardinality_histogram(&sets), vec![0, 2, 0, 1, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn cardinality_histogram(sets: &[Self]) -> Vec<usize>
+    {
+        let mut out = vec![0; N+1];
+
+        for set in sets {
+            let len = (**set & full_bits::<N,Z>()).count_ones() as usize;
+            out[len] += 1;
+        }
+
+        out
+    }
+
+    /// If the integers in the set are equally spaced, return the common difference between them in a `Some()`, otherwise return `None`.
+    /// 
+    /// A set with 2 integers always forms an arithmetic progression, while a set with 1 integer has a common difference of `0`. An empty set returns `None`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![2,4,6].is_arithmetic_progression(), Some(2) );
+    /// assert_eq!( byteset![1,2,4].is_arithmetic_progression(), None );
+    /// ```
+    pub fn is_arithmetic_progression(self) -> Option<usize>
+    {
+        let mut members = self.iter_asc();
+
+        let first = members.next()?;
+        let Some(second) = members.next() else { return Some(0) };
+
+        let step = second - first;
+        let mut prev = second;
+
+        for n in members {
+            if n - prev != step { return None; }
+            prev = n;
+        }
+
+        Some(step)
+    }
+
+    /// Count how many of `sets` contain each integer. Returns a `Vec` of length `N`, where index `i` is the number of sets containing `i+1`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let sets = [byteset![1,2], byteset![2,3], byteset![2,8]];
+    /// assert_eq!(Bitset::occurrence_counts(&sets), vec![1, 3, 1, 0, 0, 0, 0, 1]);
+    /// ```
+    pub fn occurrence_counts(sets: &[Self]) -> Vec<usize>
+    {
+        let mut out = vec![0; N];
+
+        for set in sets {
+            for i in Self(**set & full_bits::<N,Z>()).bit_indices() {
+                out[i] += 1;
+            }
+        }
+
+        out
+    }
+
+    /// Get the integers in the set in ascending order, each paired with its *dense rank*, i.e. its position in the set starting from 1.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![3,5,8].dense_ranks(), vec![(3,1), (5,2), (8,3)]);
+    /// ```
+    pub fn dense_ranks(self) -> Vec<(usize, usize)>
+    {
+        self.iter_asc()
+            .zip(1..)
+            .collect()
+    }
+
+    /// Get the positions (0-based, in ascending order) within `self` of the integers that are also in `other`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// // 2 is the 0th element of `self`, 5 is the 2nd
+    /// assert_eq!(byteset![2,3,5,7].positions_of(&byteset![1,2,5]), vec![0, 2]);
+    /// ```
+    pub fn positions_of(self, other: &Self) -> Vec<usize>
+    {
+        self.iter_asc()
+            .enumerate()
+            .filter(|&(_, n)| other.has_usize(n))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Get the minimum integer present in the set, or `None` if the set is empty.
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![].minimum(),      None);
+    /// assert_eq!(byteset![1,2,6].minimum(), Some(1));
+    /// ```
+    pub fn minimum(self) -> Option<usize>
+    {
+        let bits = self.canonical();
+        (!bits.is_empty()).then(|| bits.trailing_zeros() as usize + 1)
+    }
+
+    /// Get the maximum integer present in the set, or `None` if the set is empty.
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![].maximum(),      None);
+    /// assert_eq!(byteset![1,2,6].maximum(), Some(6));
+    /// ```
+    pub fn maximum(self) -> Option<usize>
+    {
+        /* NOTE: `.iter()` returns members in decreasing order */
+        self.iter().next()
+    }
+
+    /// Get how many integers from 1 upwards are absent before the minimum integer in the set, or `N` if the set is empty.
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![].leading_gap(),      8);
+    /// assert_eq!(byteset![3,4,6].leading_gap(), 2);
+    /// ```
+    pub fn leading_gap(self) -> usize
+    {
+        let bits = *self & full_bits::<N,Z>();
+        if bits == Z::zero() { return N; }
+
+        bits.trailing_zeros() as usize
+    }
+
+    /// Get how many integers from `N` downwards are absent after the maximum integer in the set, or `N` if the set is empty.
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!(byteset![].trailing_gap(),      8);
+    /// assert_eq!(byteset![3,4,6].trailing_gap(), 2);
+    /// ```
+    pub fn trailing_gap(self) -> usize
+    {
+        let bits = *self & full_bits::<N,Z>();
+        let maximum = bit_width::<Z>() - bits.leading_zeros() as usize;
+
+        N - maximum
+    }
+
+    /// Get the integer in the set closest to `target`, or `None` if the set is empty. If 2 integers are equally close, the smaller one is returned.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let bitset = byteset![2,6,7];
+    /// 
+    /// assert_eq!(bitset.nearest_member(6), Some(6));
+    /// assert_eq!(bitset.nearest_member(4), Some(2));
+    /// assert_eq!(bitset.nearest_member(5), Some(6));
+    /// assert_eq!(bitset.nearest_member(-1), Some(2));
+    /// ```
+    pub fn nearest_member<R>(self, target: R) -> Option<usize>
+        where R: AnyInt
+    {
+        if self.is_empty() { return None; }
+
+        let target = saturate_usize(target);
+
+        let below = self.slice(..=target);
+        let below = (!below.is_empty()).then(|| N - below.trailing_gap());
+
+        let above = self.slice(target..);
+        let above = (!above.is_empty()).then(|| above.leading_gap() + 1);
+
+        match (below, above) {
+            (Some(b), Some(a)) => Some(if target - b <= a - target { b } else { a }),
+            (b, a) => b.or(a),
+        }
+    }
+
+    /// Get the largest integer in the set that is strictly less than `x`, or `None` if there is none.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![2,5,7].highest_below(5), Some(2) );
+    /// assert_eq!( byteset![2,5,7].highest_below(2), None );
+    /// ```
+    pub fn highest_below<R>(self, x: R) -> Option<usize>
+        where R: AnyInt
+    {
+        let below = self.slice(..saturate_usize(x));
+        (!below.is_empty()).then(|| N - below.trailing_gap())
+    }
+
+    /// Get the smallest integer in the set that is strictly greater than `x`, or `None` if there is none.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![2,5,7].lowest_above(5), Some(7) );
+    /// assert_eq!( byteset![2,5,7].lowest_above(7), None );
+    /// ```
+    pub fn lowest_above<R>(self, x: R) -> Option<usize>
+        where R: AnyInt
+    {
+        let above = self.slice((ops::Bound::Excluded(saturate_usize(x)), ops::Bound::Unbounded));
+        (!above.is_empty()).then(|| above.leading_gap() + 1)
+    }
+
+    /// If the set contains consecutive integers with no gaps, return them as a range `minimum..=maximum` in a `Some()`, otherwise return `None`. An empty set returns `None`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![3,4,5].to_range(), Some(3..=5) );
+    /// assert_eq!( byteset![3,5].to_range(),   None );
+    /// assert_eq!( byteset![].to_range(),      None );
+    /// ```
+    pub fn to_range(self) -> Option<ops::RangeInclusive<usize>>
+    {
+        let lower = self.minimum()?;
+
+        self.is_consecutive_from(lower)
+            .then(|| lower ..= lower + self.len() - 1)
+    }
+
+    /// If the set contains only 1 element, return it in a `Some()`, otherwise return `None`.
+    /// 
+    /// This is more convenient and efficient than `bitset.is_single().then_some(bitset.maximum().unwrap())`, for instance.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![].only(),    None );
+    /// assert_eq!( byteset![1;8].only(), None );
+    /// assert_eq!( byteset![1].only(),   Some(1) );
+    /// assert_eq!( byteset![8].only(),   Some(8) );
+    /// ```
+    pub fn only(self) -> Option<usize>
+    {
+        self.is_single()
+            .then_some(self.trailing_zeros() as usize + 1)
+    }
+
+    /// Is the set exactly the consecutive integers `start..start+len`, with no gaps? An empty set always is.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert!(  byteset![3,4,5].is_consecutive_from(3) );
+    /// assert!( !byteset![3,4,5].is_consecutive_from(2) );
+    /// assert!( !byteset![3,5,6].is_consecutive_from(3) );
+    /// ```
+    pub fn is_consecutive_from<R>(self, start: R) -> bool
+        where R: AnyInt
+    {
+        if self.is_empty() { return true; }
+
+        let Ok(start) = start.try_into() else { return false };
+        if start < 1 || start > N { return false; }
+
+        let end = start - 1 + self.len();
+        if end > N { return false; }
+
+        *self.canonical() == low_bits::<Z>(end) - low_bits::<Z>(start - 1)
+    }
+
+    /// Return `self` unchanged if it has at most `max` elements, otherwise return a [`TooManyError`].
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert!( byteset![1,2,3].clamp_len(4).is_ok() );
+    /// assert_eq!( byteset![1;5].clamp_len(4).unwrap_err().len, 5 );
+    /// ```
+    pub fn clamp_len(self, max: usize) -> Result<Self, TooManyError>
+    {
+        let len = self.len();
+
+        if len > max {
+            return Err(TooManyError { len, max });
+        }
+
+        Ok(self)
+    }
+
+    /// In debug builds, panic if the set contains any integer greater than `max`. Does nothing in release builds.
+    /// 
+    /// This is useful for asserting that a set stays within a smaller range of integers than `1..=N`.
+    pub fn debug_check_members_le(&self, max: usize)
+    {
+        debug_assert!(
+            self.maximum().is_none_or(|n| n <= max),
+            "`Bitset` {self:?} contains integers greater than {max}"
+        );
+    }
+
+    /// Panic with `msg` if `self` is not a subset of `other`, also reporting the elements of `self` missing from `other`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust,should_panic
+    /// # use natbitset::*;
+    /// // panics with "candidates escaped: extra elements Bitset {4}"
+    /// byteset![1,4].expect_subset_of(&byteset![1,2,3], "candidates escaped");
+    /// ```
+    pub fn expect_subset_of(self, other: &Self, msg: &str)
+    {
+        if !self.is_subset(other) {
+            panic!("{msg}: extra elements {:?}", self / *other);
+        }
+    }
+
+    /// Is the underlying integer a single bitflag? (i.e. a power of 2)
+    /// 
+    /// Equivalent to [`is_single`](Self::is_single), but named for interop with bitflag APIs.
+    pub fn is_single_flag(self) -> bool {
+        let bits = *self.canonical();
+        bits != Z::zero() && bits & (bits - Z::one()) == Z::zero()
+    }
+
+    /// If the set contains only 1 element, return its bitflag (the underlying integer) in a `Some()`, otherwise return `None`.
+    /// 
+    /// This is the bitflag counterpart of [`only`](Self::only), which returns the element itself. The two are related by `flag == 1 << (element - 1)`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// assert_eq!( byteset![].flag_value(),    None );
+    /// assert_eq!( byteset![1,2].flag_value(), None );
+    /// assert_eq!( byteset![3].flag_value(),   Some(0b_0100) );
+    /// ```
+    pub fn flag_value(self) -> Option<Z> {
+        self.is_single_flag().then_some(*self.canonical())
+    }
+}
+
+// == CONVERSION METHODS == //
+/// Methods for converting the set into other sets.
+impl<Z: PosInt, const N: usize> Bitset<N,Z>
+{
+    /// Get the underlying integer as a `u8`, or `None` if it does not fit.
+    pub fn to_u8(self) -> Option<u8> {
+        nums::cast(*self)
+    }
+
+    /// Get the underlying integer as a `u16`, or `None` if it does not fit.
+    pub fn to_u16(self) -> Option<u16> {
+        nums::cast(*self)
+    }
+
+    /// Get the underlying integer as a `u32`, or `None` if it does not fit.
+    pub fn to_u32(self) -> Option<u32> {
+        nums::cast(*self)
+    }
+
+    /// Get the underlying integer as a `u64`, or `None` if it does not fit.
+    pub fn to_u64(self) -> Option<u64> {
+        nums::cast(*self)
+    }
+
+    /// Get whether each integer in `1..=N` is in the set, where index `i` indicates whether `i+1` is in the set.
+    /// 
+    /// See [`flags`](Self::flags) for a lazy version.
+    pub fn to_vec_bool(self) -> Vec<bool> {
+        self.flags().collect()
+    }
+
+    /// Split the set into byte-sized sets, where chunk `c` holds the integers `8c+1..=8c+8` remapped to `1..=8`.
     /// 
-    /// Non-borrowed form of [`contains`](Self::contains).
-    pub fn has<R>(self, int: R) -> bool
-        where R: AnyInt
+    /// There are `N.div_ceil(8)` chunks, so the last chunk only covers part of `1..=8` if `N` is not a multiple of `8`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let chunks = Bitset::<16, u16>::from([1,8,9,12]).byte_chunks();
+    /// assert_eq!(chunks, vec![byteset![1,8], byteset![1,4]]);
+    /// ```
+    pub fn byte_chunks(self) -> Vec<Bitset<8, u8>>
     {
-        if let Ok(val) = int.try_into() {
-            self.iter().any(|n| n == val)
-        }
-        else {
-            false
-        }
+        let bits = *self.canonical();
+
+        (0..N.div_ceil(8))
+            .map(|c| {
+                let shift = 8 * c;
+                if shift >= bit_width::<Z>() { return Bitset(0); }
+
+                Bitset(nums::cast((bits >> shift) & low_bits(8)).unwrap_or_default())
+            })
+            .collect()
     }
 
-    /// Get the integers in the set.
+    /// Embed the set into a set with a larger range of integers `1..=M`, keeping the same elements.
     /// 
-    /// If you only need to iterate over the integers lazily, prefer using [`.iter()`](Self::iter).
+    /// `M < N`, or a `Z2` with fewer than `M` bits, is rejected at compile time. Any bits of `self` above `N` are not carried over.
     /// 
     /// # Usage
     /// 
     /// ```rust
     /// # use natbitset::*;
-    /// use std::collections::HashSet;
+    /// let digits = Bitset::<9, u16>::from([1,5,9]);
+    /// let board: Bitset<81, u128> = digits.embed();
     /// 
-    /// let bitset = byteset![1,3,7];
-    /// let nums = bitset.members();
+    /// assert_eq!(board, Bitset::<81, u128>::from([1,5,9]));
+    /// ```
     /// 
-    /// assert_eq!(nums, HashSet::from([1,3,7]));
+    /// ```rust,compile_fail
+    /// # use natbitset::*;
+    /// let shrunk: Bitset<4> = byteset![1].embed();
     /// ```
-    pub fn members(self) -> HashSet<usize>
+    pub fn embed<const M: usize, Z2: PosInt>(self) -> Bitset<M, Z2>
     {
-        let mut out = HashSet::new();
-        let mut residue = *self;
-        let mut power_of_2 = Z::one() << (N-1);
-
-        for i in (1..=N).rev() {
-            if residue >= power_of_2 {
-                residue -= power_of_2;
-                out.insert(i);
-            }
-            
-            power_of_2 >>= Z::one();
-        }
+        const { assert!(N <= M, "cannot embed a `Bitset` into one with a smaller range of integers") }
+        let () = Bitset::<M, Z2>::CAPACITY_CHECK;
 
-        out
+        /* NOTE: the canonical bits are below `2^N`, so always fit in a `Z2` with at least `M >= N` bits */
+        Bitset(nums::cast::<Z, Z2>(*self.canonical()).unwrap())
     }
 
-    /// Get the integers in the set, sorted in ascending order.
+    /// Map each integer in the set through `f` into a set with integers `1..=M`, ignoring any outputs outside this range.
     /// 
-    /// # Notes
+    /// # Usage
     /// 
-    /// - This is slightly more expensive than [`members_desc`](Self::members_desc) since it requires reversing the output of [`members_desc`](Self::members_desc).
-    ///   - This is a limitation of how the integers present in the set are determined from the bitflags.
-    pub fn members_asc(self) -> Vec<usize>
+    /// ```rust
+    /// # use natbitset::*;
+    /// let tens: Bitset<100, u128> = byteset![1,2].map_into(|n| n * 10);
+    /// assert_eq!(tens, Bitset::<100, u128>::from([10,20]));
+    /// ```
+    pub fn map_into<const M: usize, Z2: PosInt>(self, f: impl FnMut(usize) -> usize) -> Bitset<M, Z2>
     {
-        let mut out = self.members_desc();
-        out.reverse();
-        out
-    }
+        let () = Bitset::<M, Z2>::CAPACITY_CHECK;
 
-    /// Get the integers in the set, sorted in descending order.
-    pub fn members_desc(self) -> Vec<usize>
-    {
-        self.into_iter().collect::<Vec<usize>>()
+        let mut out = Z2::zero();
+
+        for m in self.iter_asc().map(f) {
+            if 1 <= m && m <= M {
+                out |= Z2::one() << (m - 1);
+            }
+        }
+
+        Bitset(out)
     }
 
-    /// Get the minimum integer present in the set, or `None` if the set is empty.
+    /// Compress the set by removing gaps between its integers, so that its `k`-th smallest integer becomes `k`. The output is hence always the integers `1..=len`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the set has more than `M` elements.
+    /// 
+    /// # Usage
     /// 
     /// ```rust
     /// # use natbitset::*;
-    /// assert_eq!(byteset![].minimum(),      None);
-    /// assert_eq!(byteset![1,2,6].minimum(), Some(1));
+    /// let sparse = Bitset::<9, u16>::from([2,5,8]);
+    /// let dense: Bitset<3, u16> = sparse.compress();
+    /// 
+    /// assert_eq!(dense, Bitset::<3, u16>::all());
     /// ```
-    pub fn minimum(self) -> Option<usize>
+    pub fn compress<const M: usize>(self) -> Bitset<M,Z>
     {
-        self.iter().min()
+        let () = Bitset::<M,Z>::CAPACITY_CHECK;
+
+        let len = self.len();
+
+        if len > M {
+            panic!("Error compressing `Bitset`: {len} elements cannot fit in integers `1..={M}`");
+        }
+
+        Bitset(low_bits(len))
     }
 
-    /// Get the maximum integer present in the set, or `None` if the set is empty.
+    /// Decompress the set using the original set `template`, so that each integer `k` becomes the `k`-th smallest integer of `template`. Integers greater than the size of `template` are ignored.
+    /// 
+    /// This is the reverse of [`compress`](Self::compress), and can also map any subset of the compressed set back into `template`.
+    /// 
+    /// # Usage
     /// 
     /// ```rust
     /// # use natbitset::*;
-    /// assert_eq!(byteset![].maximum(),      None);
-    /// assert_eq!(byteset![1,2,6].maximum(), Some(6));
+    /// let template = Bitset::<9, u16>::from([2,5,8]);
+    /// let dense = Bitset::<3, u16>::from([1,3]);
+    /// 
+    /// assert_eq!(dense.decompress(&template), Bitset::<9, u16>::from([2,8]));
     /// ```
-    pub fn maximum(self) -> Option<usize>
+    pub fn decompress<const M: usize>(self, template: &Bitset<M,Z>) -> Bitset<M,Z>
     {
-        /* NOTE: `.iter()` returns members in decreasing order */
-        self.iter().next()
+        let mut out = Bitset::<M,Z>::none();
+
+        for (k, n) in template.iter_asc().enumerate() {
+            if self.has_usize(k+1) {
+                out.insert_usize(n);
+            }
+        }
+
+        out
     }
 
-    /// If the set contains only 1 element, return it in a `Some()`, otherwise return `None`.
+    /// Interleave the bits of `self` and `other` into a set of twice the size (Morton order), with `self` occupying the even bits and `other` the odd bits.
     /// 
-    /// This is more convenient and efficient than `bitset.is_single().then_some(bitset.maximum().unwrap())`, for instance.
+    /// Hence an integer `n` in `self` maps to `2n - 1` in the output, while an integer `n` in `other` maps to `2n`.
+    /// 
+    /// `M < 2 * N`, where the output would not be able to represent every interleaved integer, or a `Z2` with fewer than `M` bits, is rejected at compile time.
     /// 
     /// # Usage
     /// 
     /// ```rust
     /// # use natbitset::*;
-    /// assert_eq!( byteset![].only(),    None );
-    /// assert_eq!( byteset![1;8].only(), None );
-    /// assert_eq!( byteset![1].only(),   Some(1) );
-    /// assert_eq!( byteset![8].only(),   Some(8) );
+    /// let evens = Bitset::<4>(0b_0011);
+    /// let odds  = Bitset::<4>(0b_0101);
+    /// 
+    /// let interleaved: Bitset<8> = evens.interleave(odds);
+    /// assert_eq!(*interleaved, 0b_0010_0111);
     /// ```
-    pub fn only(self) -> Option<usize>
+    /// 
+    /// ```rust,compile_fail
+    /// # use natbitset::*;
+    /// let cramped: Bitset<7> = Bitset::<4>::all().interleave(Bitset::<4>::all());
+    /// ```
+    pub fn interleave<const M: usize, Z2: PosInt>(self, other: Self) -> Bitset<M, Z2>
     {
-        self.is_single()
-            .then_some(self.trailing_zeros() as usize + 1)
+        const { assert!(2 * N <= M, "cannot interleave 2 `Bitset`s into one with fewer than twice the range of integers") }
+        let () = Bitset::<M, Z2>::CAPACITY_CHECK;
+
+        let mut out = Z2::zero();
+
+        for n in self {
+            out |= Z2::one() << (2*n - 2);
+        }
+        for n in other {
+            out |= Z2::one() << (2*n - 1);
+        }
+
+        Bitset(out)
     }
 }
 
+// == CONST METHODS == //
+/// Implement `const` methods for a `Bitset` backed by each of the given concrete integer types.
+/// 
+/// These can't be implemented for a generic `Z`, since trait methods (such as those of `BitAnd`) can't be called in a `const` context.
+macro_rules! impl_const_ops {
+    ( $( $z:ty ),* ) =>
+    {
+        $(
+            /// Methods for operating on the set in a `const` context.
+            impl<const N: usize> Bitset<N, $z>
+            {
+                /// Construct a set with a single integer `n`, checking that it is in the range `1..=N`.
+                /// 
+                /// This is intended for constructing sets from literals in a `const` context, where an out-of-range `n` is caught at compile time. Outside a `const` context, the check panics at runtime instead. For arbitrary integer types, use [`single`](Self::single).
+                /// 
+                /// # Panics
+                /// 
+                /// Panics if `n` is not in the range `1..=N`.
+                /// 
+                /// # Usage
+                /// 
+                /// ```rust
+                /// # use natbitset::*;
+                /// const THREE: Bitset<4> = Bitset::<4>::single_checked(3);
+                /// assert_eq!(THREE, Bitset::<4>::single(3));
+                /// ```
+                /// 
+                /// ```rust,compile_fail
+                /// # use natbitset::*;
+                /// const FIVE: Bitset<4> = Bitset::<4>::single_checked(5);
+                /// ```
+                pub const fn single_checked(n: usize) -> Self {
+                    let () = Self::CAPACITY_CHECK;
+                    assert!(1 <= n && n <= N, "integer is outside of the range `1..=N` of the `Bitset`");
+                    Bitset(1 << (n - 1))
+                }
+
+                /// Return the intersection of `self` and the raw bits `mask`. Can be used in a `const` context.
+                /// 
+                /// See [`intersection`](Self::intersection) for more info.
+                pub const fn and_const(self, mask: $z) -> Self {
+                    Bitset(self.0 & mask)
+                }
+
+                /// Return the union of `self` and the raw bits `mask`. Can be used in a `const` context.
+                /// 
+                /// See [`union`](Self::union) for more info.
+                pub const fn or_const(self, mask: $z) -> Self {
+                    Bitset(self.0 | mask)
+                }
+
+                /// Return the difference of `self` and the raw bits `mask`. Can be used in a `const` context.
+                /// 
+                /// See [`difference`](Self::difference) for more info.
+                pub const fn diff_const(self, mask: $z) -> Self {
+                    Bitset(self.0 & !mask)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_ops!(u8, u16, u32, u64, u128, usize);
+
+/// A set operation to apply with a raw mask, used by [`Bitset::apply_mask`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MaskOp {
+    /// Add the bits of the mask, as with `|`.
+    Union,
+    /// Keep only the bits of the mask, as with `&`.
+    Intersect,
+    /// Remove the bits of the mask, as with `/`.
+    Difference,
+    /// Toggle the bits of the mask, as with `^`.
+    Toggle,
+}
+
 // == MUTATING METHODS == //
 /// Specialised methods for mutating the set.
 impl<Z: PosInt, const N: usize> Bitset<N,Z>
@@ -835,6 +2596,35 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         if let Err(e) = self.intersect_nonempty(other) { panic!("{e}") }
     }
 
+    /// (in-place) Intersect `self` with the singleton set `{int}`. If `int` is not in `self`, return an [`EmptiedBitsetError`], leaving `self` unchanged.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut cell = byteset![2,5,7];
+    /// 
+    /// assert!( cell.intersect_nonempty_int(4).is_err() );
+    /// assert!( cell.intersect_nonempty_int(5).is_ok() );
+    /// assert_eq!( cell, byteset![5] );
+    /// ```
+    pub fn intersect_nonempty_int<R>(&mut self, int: R) -> Result<(), Box<dyn Error + 'static>>
+        where R: AnyInt + fmt::Debug
+    {
+        let mut single = Self::none();
+        if let Ok(n) = int.try_into() { single.insert_usize(n); }
+
+        let intersect = *self & single;
+
+        if intersect.is_empty() {
+            boxerr!(EmptiedBitsetError => "intersecting `{self:?}` with `{{{int:?}}}` resulted in empty bitset");
+        }
+
+        *self = intersect;
+
+        Ok(())
+    }
+
     /// Remove `int` from `self`, first by trying to convert `int` to `usize`. If `self` becomes empty as a result, return an [`EmptiedBitsetError`], leaving `self` unchanged.
     /// 
     /// See [`try_remove`](Self::try_remove) for more info.
@@ -866,23 +2656,34 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
         if let Err(e) = self.remove_nonempty(int) { panic!("{e}") }
     }
 
-    /// (in-place) Filter `self` to keep only elements that fulfil `predicate`. If `self` becomes empty as a result, return an [`EmptiedBitsetError`], leaving `self` unchanged.
+    /// (in-place) Filter `self` to keep only elements that fulfil `predicate`, returning the number of elements removed. If `self` becomes empty as a result, return an [`EmptiedBitsetError`], leaving `self` unchanged.
     /// 
     /// See [`retain`](Self::retain) for more info.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1,2,3,4];
+    /// 
+    /// assert_eq!( bitset.retain_nonempty(|n| n % 2 == 0).unwrap(), 2 );
+    /// assert_eq!( bitset, byteset![2,4] );
+    /// ```
     pub fn retain_nonempty(&mut self,
         predicate: impl FnMut(usize) -> bool,
-    ) -> Result<(), Box<dyn Error + 'static>>
+    ) -> Result<usize, Box<dyn Error + 'static>>
     {
-        let mut copy = self.clone();
+        let mut copy = *self;
         copy.retain(predicate);
 
         if copy.is_empty() {
             boxerr!(EmptiedBitsetError => "matching elements of {copy:?} against predicate resulted in empty bitset")
         }
 
+        let removed = self.len() - copy.len();
         *self = copy;
 
-        Ok(())
+        Ok(removed)
     }
 
     /// Filter `self` to keep only elements that fulfil `predicate`, panicking if `self` becomes empty as a result.
@@ -892,13 +2693,220 @@ impl<Z: PosInt, const N: usize> Bitset<N,Z>
     {
         if let Err(e) = self.retain_nonempty(predicate) { panic!("{e}") }
     }
+
+    /// (in-place) Insert the smallest integers not in `self` until it has `k` elements, or is full. Since `self` cannot have more than `N` elements, `k > N` fills the set.
+    /// 
+    /// This is the reverse of [`truncate_to_len`](Self::truncate_to_len).
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![2,4];
+    /// bitset.fill_to_len(4);
+    /// assert_eq!(bitset, byteset![1,2,3,4]);
+    /// ```
+    pub fn fill_to_len(&mut self, k: usize)
+    {
+        while self.len() < k {
+            if self.allocate().is_none() { break; }
+        }
+    }
+
+    /// (in-place) Keep only the `k` elements of `self` with the largest `key`, removing the rest. Elements with equal keys are ranked by value, with smaller elements kept first. Does nothing if `self` already has at most `k` elements.
+    /// 
+    /// See [`truncate_to_len`](Self::truncate_to_len) to keep the smallest elements instead.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1;6];
+    /// bitset.retain_top_k_by(2, |n| -(n as i64 - 4).abs());
+    /// assert_eq!(bitset, byteset![3,4]);
+    /// ```
+    pub fn retain_top_k_by(&mut self, k: usize, mut key: impl FnMut(usize) -> i64)
+    {
+        if self.len() <= k { return; }
+
+        let mut ranked = self.iter_asc()
+            .map(|n| (key(n), n))
+            .collect::<Vec<_>>();
+
+        ranked.sort_by(|(key_l, n_l), (key_r, n_r)| key_r.cmp(key_l).then(n_l.cmp(n_r)));
+
+        self.clear();
+        for (_, n) in ranked.into_iter().take(k) {
+            self.insert_usize(n);
+        }
+    }
+
+    /// (in-place) Apply the raw bits `mask` to `self` under the set operation `op`. Any bits above `N` are then disabled.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = Bitset::<4>::from([1,2]);
+    /// 
+    /// bitset.apply_mask(0b_1111_1100, MaskOp::Union);
+    /// assert_eq!(bitset, Bitset::<4>::from([1,2,3,4]));
+    /// 
+    /// bitset.apply_mask(0b_0101, MaskOp::Toggle);
+    /// assert_eq!(bitset, Bitset::<4>::from([2,4]));
+    /// ```
+    pub fn apply_mask(&mut self, mask: Z, op: MaskOp)
+    {
+        let bits = match op {
+            MaskOp::Union      => **self | mask,
+            MaskOp::Intersect  => **self & mask,
+            MaskOp::Difference => **self & !mask,
+            MaskOp::Toggle     => **self ^ mask,
+        };
+
+        **self = bits & full_bits::<N,Z>();
+    }
+
+    /// (in-place) Toggle the elements of `other` in `self`, i.e. take the symmetric difference. Returns the elements that were added and removed, respectively.
+    /// 
+    /// See [`symmetric_difference`](Self::symmetric_difference) for more info.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1,2,3];
+    /// let (added, removed) = bitset.toggle_all(&byteset![3,4]);
+    /// 
+    /// assert_eq!(bitset, byteset![1,2,4]);
+    /// assert_eq!(added, byteset![4]);
+    /// assert_eq!(removed, byteset![3]);
+    /// ```
+    pub fn toggle_all(&mut self, other: &Self) -> (Self, Self)
+    {
+        let added = *other / *self;
+        let removed = *other & *self;
+
+        *self ^= *other;
+
+        (added, removed)
+    }
+
+    /// (in-place) Toggle every integer in `lo..=hi`, clamped to `1..=N`. Does nothing if `lo > hi`.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1,2,3];
+    /// bitset.toggle_range(2, 5);
+    /// 
+    /// assert_eq!(bitset, byteset![1,4,5]);
+    /// ```
+    pub fn toggle_range<R>(&mut self, lo: R, hi: R)
+        where R: AnyInt
+    {
+        *self ^= Self(range_bits::<N,Z>(saturate_usize(lo), saturate_usize(hi)));
+    }
+
+    /// Treating the set as the allocated slots of an allocator, find the smallest integer not in the set, insert it, and return it in a `Some()`. Returns `None` if the set is full.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut slots = Bitset::<2>::none();
+    /// 
+    /// assert_eq!(slots.allocate(), Some(1));
+    /// assert_eq!(slots.allocate(), Some(2));
+    /// assert_eq!(slots.allocate(), None);
+    /// 
+    /// slots.free(1);
+    /// assert_eq!(slots.allocate(), Some(1));
+    /// ```
+    pub fn allocate(&mut self) -> Option<usize>
+    {
+        let slot = self.trailing_ones() as usize + 1;
+        if slot > N { return None; }
+
+        self.insert_usize(slot);
+        Some(slot)
+    }
+
+    /// Treating the set as the allocated slots of an allocator, remove `slot` from the set. Returns whether `slot` was allocated.
+    /// 
+    /// See [`allocate`](Self::allocate) for more info.
+    pub fn free(&mut self, slot: usize) -> bool
+    {
+        if !self.has_usize(slot) { return false; }
+
+        **self &= !(Z::one() << (slot - 1));
+        true
+    }
+
+    /// (in-place) Keep only the `k` smallest elements of `self`, removing the rest. Does nothing if `self` already has at most `k` elements.
+    /// 
+    /// # Usage
+    /// 
+    /// ```rust
+    /// # use natbitset::*;
+    /// let mut bitset = byteset![1,2,3,4,5];
+    /// bitset.truncate_to_len(3);
+    /// assert_eq!(bitset, byteset![1,2,3]);
+    /// ```
+    pub fn truncate_to_len(&mut self, k: usize)
+    {
+        let mut res = Z::zero();
+        let mut residue = *self.canonical();
+
+        for _ in 0..k {
+            if residue == Z::zero() { break; }
+
+            let lowest = residue & !(residue - Z::one());
+            res |= lowest;
+            residue -= lowest;
+        }
+
+        **self = res;
+    }
+}
+
+
+/// The bits of a full `Bitset<N,Z>`, i.e. the lowest `N` bits enabled.
+fn full_bits<const N: usize, Z: PosInt>() -> Z
+{
+    low_bits(N)
+}
+
+/// The number of bits in a `Z`.
+fn bit_width<Z: PosInt>() -> usize
+{
+    Z::zero().count_zeros() as usize
+}
+
+/// A `Z` with only its lowest `k` bits enabled.
+fn low_bits<Z: PosInt>(k: usize) -> Z
+{
+    if k >= bit_width::<Z>() { Z::max_value() }
+    else { (Z::one() << k) - Z::one() }
 }
 
+/// A `Z` with the bits for the integers `lower..=upper` enabled, clamped to `1..=N`. Inverted ranges give no bits.
+fn range_bits<const N: usize, Z: PosInt>(lower: usize, upper: usize) -> Z
+{
+    let (lower, upper) = (lower.max(1), upper.min(N));
+    if lower > upper { return Z::zero(); }
+
+    low_bits::<Z>(upper) - low_bits::<Z>(lower - 1)
+}
 
-/// Cast a `usize` into a non-negative `Z`.
-fn into_z<Z: PosInt>(u: usize) -> Z
+/// Cast an integer into a `usize`, saturating at `0` and `usize::MAX` if it is out of range.
+fn saturate_usize<R: AnyInt>(r: R) -> usize
 {
-    nums::cast::<usize, Z>(u).unwrap()
+    match r.try_into() {
+        Ok(u) => u,
+        Err(_) => if r < R::zero() { 0 } else { usize::MAX },
+    }
 }
 
 /// Cast an integer into a `usize`.
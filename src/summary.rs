@@ -0,0 +1,29 @@
+use crate::*;
+
+
+/// An overview of a [`Bitset`], produced by [`Bitset::summary`].
+/// 
+/// # Usage
+/// 
+/// ```rust
+/// # use natbitset::*;
+/// let summary = Bitset::<4>::from([1,3]).summary();
+/// 
+/// assert_eq!(summary.present, Bitset::<4>::from([1,3]));
+/// assert_eq!(summary.absent, Bitset::<4>::from([2,4]));
+/// assert_eq!(summary.len, 2);
+/// assert_eq!(summary.density, 0.5);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BitsetSummary<const N: usize, Z = u8>
+    where Z: PosInt
+{
+    /// The integers in the set.
+    pub present: Bitset<N,Z>,
+    /// The integers in `1..=N` not in the set.
+    pub absent: Bitset<N,Z>,
+    /// The number of integers in the set.
+    pub len: usize,
+    /// The fraction of integers in `1..=N` that are in the set. See [`Bitset::fill_fraction`].
+    pub density: f64,
+}
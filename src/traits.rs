@@ -50,3 +50,39 @@ impl<T> PosInt for T where T:
     + ops::Shr<Output = Self> + ops::ShrAssign
     + iter::Sum
 {}
+
+
+/// A marker for a `Bitset` with integers `1..=N`, used to select its smallest backing integer type.
+/// 
+/// You shouldn't need to use this directly – see [`bitset_type!`](crate::bitset_type) instead.
+pub struct Capacity<const N: usize>;
+
+/// The smallest unsigned integer type able to store `N` bitflags, implemented for [`Capacity<N>`] for `N` in `1..=128`.
+/// 
+/// You shouldn't need to use this directly – see [`bitset_type!`](crate::bitset_type) instead.
+pub trait Backing {
+    type Z: PosInt;
+}
+
+macro_rules! impl_backing {
+    ( $z:ty => $( $n:literal )* ) =>
+    {
+        $( impl Backing for Capacity<$n> { type Z = $z; } )*
+    };
+}
+
+impl_backing!(u8 => 1 2 3 4 5 6 7 8);
+impl_backing!(u16 => 9 10 11 12 13 14 15 16);
+impl_backing!(u32 =>
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+);
+impl_backing!(u64 =>
+    33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48
+    49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64
+);
+impl_backing!(u128 =>
+    65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80
+    81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96
+    97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112
+    113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128
+);
@@ -0,0 +1,30 @@
+#![cfg(feature = "bitvec")]
+
+use bitvec::prelude::*;
+
+use natbitset::*;
+
+
+#[test] fn to_bitvec()
+{
+    assert_eq!( Bitset::<4>::none().to_bitvec(), bitvec![0, 0, 0, 0] );
+    assert_eq!( Bitset::<4>::from([1,3]).to_bitvec(), bitvec![1, 0, 1, 0] );
+    assert_eq!( Bitset::<4>(0b_1111_1000).to_bitvec(), bitvec![0, 0, 0, 1] );
+}
+
+#[test] fn from_bitvec()
+{
+    assert_eq!( Bitset::<4>::from_bitvec(bits![]), Bitset::<4>::none() );
+    assert_eq!( Bitset::<4>::from_bitvec(bits![1, 0, 1]), Bitset::<4>::from([1,3]) );
+    assert_eq!( Bitset::<4>::from_bitvec(bits![0, 1, 0, 1, 1, 1]), Bitset::<4>::from([2,4]) );
+}
+
+#[test] fn bitvec_roundtrip()
+{
+    for bitset in [byteset![], byteset![1], byteset![2,4,7], byteset![1;8]] {
+        assert_eq!( Bitset::<8>::from_bitvec(&bitset.to_bitvec()), bitset );
+    }
+
+    let wide = Bitset::<81, u128>::from([1,40,81]);
+    assert_eq!( Bitset::<81, u128>::from_bitvec(&wide.to_bitvec()), wide );
+}
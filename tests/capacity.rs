@@ -0,0 +1,7 @@
+/* NOTE: the `pass` case makes trybuild fully build each case, since the capacity assert is only evaluated after monomorphisation, which `cargo check` skips */
+#[test] fn undersized_z_fails_to_compile()
+{
+    let t = trybuild::TestCases::new();
+    t.pass("tests/compile_pass/*.rs");
+    t.compile_fail("tests/compile_fail/*.rs");
+}
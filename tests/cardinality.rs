@@ -0,0 +1,34 @@
+use natbitset::*;
+
+
+#[test] fn by_cardinality()
+{
+    assert!( ByCardinality(byteset![]) < ByCardinality(byteset![8]) );
+    assert!( ByCardinality(byteset![8]) < ByCardinality(byteset![1,2]) );
+    assert!( ByCardinality(byteset![1,2]) < ByCardinality(byteset![1,3]) );
+    assert!( ByCardinality(byteset![1,3]) < ByCardinality(byteset![1;8]) );
+
+    assert_eq!( ByCardinality(byteset![1,3]), ByCardinality(byteset![1,3]) );
+}
+
+#[test] fn by_cardinality_sort()
+{
+    let mut sets = vec![
+        ByCardinality(byteset![1;8]),
+        ByCardinality(byteset![3,5]),
+        ByCardinality(byteset![6]),
+        ByCardinality(byteset![1,2]),
+        ByCardinality(byteset![]),
+        ByCardinality(byteset![2]),
+    ];
+    sets.sort();
+
+    assert_eq!( sets.into_iter().map(|set| set.0).collect::<Vec<_>>(), vec![
+        byteset![],
+        byteset![2],
+        byteset![6],
+        byteset![1,2],
+        byteset![3,5],
+        byteset![1;8],
+    ]);
+}
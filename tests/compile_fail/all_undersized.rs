@@ -0,0 +1,5 @@
+use natbitset::*;
+
+fn main() {
+    let _ = Bitset::<9, u8>::all();
+}
@@ -0,0 +1,5 @@
+use natbitset::*;
+
+fn main() {
+    let _ = Bitset::<9, u16>::from([1]).embed::<81, u8>();
+}
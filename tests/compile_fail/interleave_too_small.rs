@@ -0,0 +1,5 @@
+use natbitset::*;
+
+fn main() {
+    let _ = byteset![1].interleave::<10, u16>(byteset![1]);
+}
@@ -0,0 +1,5 @@
+use natbitset::*;
+
+fn main() {
+    let _ = byteset![8].interleave::<16, u8>(byteset![8]);
+}
@@ -0,0 +1,5 @@
+use natbitset::*;
+
+fn main() {
+    let _ = byteset![1,2].map_into::<16, u8>(|n| n * 2);
+}
@@ -0,0 +1,9 @@
+use natbitset::*;
+
+fn main() {
+    let _ = Bitset::<9, u16>::all();
+    let _ = Bitset::<9, u16>::default();
+    let _ = byteset![1,2].map_into::<16, u16>(|n| n * 2);
+    let _ = byteset![8].interleave::<16, u16>(byteset![8]);
+    let _ = Bitset::<9, u16>::from([1]).embed::<81, u128>();
+}
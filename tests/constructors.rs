@@ -11,6 +11,19 @@ use natbitset::*;
 #[test] #[should_panic] fn single_zero() { Bitset::<1>::single(0); }
 #[test] #[should_panic] fn single_exceed() { Bitset::<1>::single(2); }
 
+#[test] fn single_checked()
+{
+    const ONE: Bitset<1> = Bitset::<1>::single_checked(1);
+    const NINE: Bitset<9, u16> = Bitset::<9, u16>::single_checked(9);
+
+    assert_eq!( *ONE, 1 );
+    assert_eq!( NINE, Bitset::<9, u16>::single(9) );
+    assert_eq!( Bitset::<8>::single_checked(4), byteset![4] );
+}
+
+#[test] #[should_panic] fn single_checked_zero() { Bitset::<1>::single_checked(0); }
+#[test] #[should_panic] fn single_checked_exceed() { Bitset::<1>::single_checked(2); }
+
 #[test] fn none()
 {
     assert_eq!( *Bitset::<1>::none(), 0 );
@@ -27,6 +40,61 @@ use natbitset::*;
     assert_eq!( *Bitset::<8>::all(), 0b_1111_1111 );
 }
 
+#[test] fn all_large()
+{
+    assert_eq!( *Bitset::<9, u16>::all(), 0b_0000_0001_1111_1111 );
+    assert_eq!( *Bitset::<16, u16>::all(), u16::MAX );
+    assert_eq!( *Bitset::<32, u32>::all(), u32::MAX );
+    assert_eq!( *Bitset::<63, u64>::all(), u64::MAX >> 1 );
+    assert_eq!( *Bitset::<64, u64>::all(), u64::MAX );
+    assert_eq!( *Bitset::<81, u128>::all(), (1 << 81) - 1 );
+    assert_eq!( *Bitset::<128, u128>::all(), u128::MAX );
+    assert_eq!( Bitset::<128, u128>::all().len(), 128 );
+}
+
+#[test] fn try_all()
+{
+    assert_eq!( *Bitset::<4>::try_all().unwrap(), 0b_1111 );
+    assert_eq!( *Bitset::<8>::try_all().unwrap(), u8::MAX );
+    assert_eq!( *Bitset::<128, u128>::try_all().unwrap(), u128::MAX );
+
+    assert_eq!( Bitset::<9, u8>::try_all(), Err(CapacityError { n: 9, z_bits: 8 }) );
+    assert_eq!( Bitset::<129, u128>::try_all(), Err(CapacityError { n: 129, z_bits: 128 }) );
+}
+
+#[test] fn aliases()
+{
+    assert_eq!( Bitset::<1>::empty(), Bitset::<1>::none() );
+    assert_eq!( Bitset::<8>::empty(), Bitset::<8>::none() );
+
+    assert_eq!( Bitset::<1>::full(), Bitset::<1>::all() );
+    assert_eq!( Bitset::<8>::full(), Bitset::<8>::all() );
+
+    assert_eq!( Bitset::<8>::default(), Bitset::<8>::none() );
+    assert_eq!( Bitset::<9, u16>::default(), Bitset::<9, u16>::none() );
+}
+
+#[test] fn singletons()
+{
+    assert_eq!( Bitset::<1>::singletons(), [Bitset::<1>::from([1])] );
+    assert_eq!( Bitset::<4>::singletons()[2], Bitset::<4>::from([3]) );
+    assert_eq!( Bitset::<8>::singletons().into_iter().map(|b| *b).sum::<u8>(), 0b_1111_1111 );
+    assert_eq!( Bitset::<9, u16>::singletons()[8], Bitset::<9, u16>::from([9]) );
+}
+
+#[test] fn stride()
+{
+    assert_eq!( Bitset::<8>::stride(3), byteset![1,4,7] );
+    assert_eq!( Bitset::<8>::stride(2), byteset![1,3,5,7] );
+    assert_eq!( Bitset::<8>::stride(7), byteset![1,8] );
+    assert_eq!( Bitset::<8>::stride(99), byteset![1] );
+    assert_eq!( Bitset::<8>::stride(1), byteset![1;8] );
+    assert_eq!( Bitset::<8>::stride(0), byteset![1] );
+    assert_eq!( Bitset::<0>::stride(1), Bitset::<0>::none() );
+    assert_eq!( Bitset::<0>::stride(0), Bitset::<0>::none() );
+    assert_eq!( Bitset::<81, u128>::stride(40), Bitset::<81, u128>::from([1,41,81]) );
+}
+
 #[test] fn from_array()
 {
     assert_eq!( *Bitset::<4>::from([1,2,3]), 0b_0111 );
@@ -40,6 +108,33 @@ use natbitset::*;
     assert_eq!( *Bitset::<8>::from_iter(vec![2,4,7,99]), 0b_0100_1010 );
 }
 
+#[test] fn from_iter_duplicates()
+{
+    assert_eq!( *Bitset::<8>::from_iter([1,1]), 0b_0001 );
+    assert_eq!( *Bitset::<8>::from_iter([1,1,1]), *byteset![1] );
+    assert_eq!( *Bitset::<8>::from_iter([2,2,3,2]), 0b_0110 );
+    assert_eq!( *Bitset::<8>::from_iter([8,8]), 0b_1000_0000 );
+}
+
+#[test] fn constructors_idempotent()
+{
+    let expected = Bitset::<8>(0b_0000_0111);
+
+    for input in [[1,2,3,3,3], [3,1,3,2,1], [2,2,1,1,3], [3,2,1,2,3], [1,1,1,2,3]] {
+        assert_eq!( *Bitset::<8>::from_iter(input), *expected );
+        assert_eq!( *Bitset::<8>::from_iter(input.to_vec()), *expected );
+        assert_eq!( *Bitset::<8>::from(input), *expected );
+
+        let mut extended = Bitset::<8>::none();
+        extended.extend(input);
+        assert_eq!( *extended, *expected );
+    }
+
+    assert_eq!( *byteset![3,1,3,2,1], *byteset![1,2,3] );
+    assert_eq!( *byteset![8,8,1,8,1], *byteset![1,8] );
+    assert_eq!( *Bitset::<16, u16>::from([16,9,16,9,9]), 0b_1000_0001_0000_0000 );
+}
+
 #[test] fn byteset_macro()
 {
     assert_eq!( *byteset![1], 0b_0001 );
@@ -49,3 +144,20 @@ use natbitset::*;
     assert_eq!( byteset![1;8], Bitset::<8, u8>::from([1,2,3,4,5,6,7,8]) );
     assert_eq!( byteset![1;8], Bitset::<8, u8>::from_iter(1..=8) );
 }
+
+#[test] fn bitset_type_macro()
+{
+    let _: Bitset<1, u8> = <bitset_type!(1)>::none();
+    let _: Bitset<8, u8> = <bitset_type!(8)>::none();
+    let _: Bitset<9, u16> = <bitset_type!(9)>::none();
+    let _: Bitset<16, u16> = <bitset_type!(16)>::none();
+    let _: Bitset<17, u32> = <bitset_type!(17)>::none();
+    let _: Bitset<32, u32> = <bitset_type!(32)>::none();
+    let _: Bitset<33, u64> = <bitset_type!(33)>::none();
+    let _: Bitset<64, u64> = <bitset_type!(64)>::none();
+    let _: Bitset<65, u128> = <bitset_type!(65)>::none();
+    let _: Bitset<128, u128> = <bitset_type!(128)>::none();
+
+    const SIZE: usize = 81;
+    let _: Bitset<81, u128> = <bitset_type!(SIZE)>::none();
+}
@@ -0,0 +1,130 @@
+use natbitset::*;
+
+
+#[test] fn to_uint()
+{
+    let small = Bitset::<16, u16>::from([1,8]);
+    assert_eq!( small.to_u8(), Some(0b_1000_0001) );
+    assert_eq!( small.to_u16(), Some(0b_1000_0001) );
+    assert_eq!( small.to_u32(), Some(0b_1000_0001) );
+    assert_eq!( small.to_u64(), Some(0b_1000_0001) );
+
+    let large = Bitset::<64, u64>::from([1,9,17,33]);
+    assert_eq!( large.to_u8(), None );
+    assert_eq!( large.to_u16(), None );
+    assert_eq!( large.to_u32(), None );
+    assert_eq!( large.to_u64(), Some((1 << 32) + (1 << 16) + (1 << 8) + 1) );
+
+    let wide = Bitset::<128, u128>::from([65]);
+    assert_eq!( wide.to_u64(), None );
+}
+
+#[test] fn to_vec_bool()
+{
+    assert_eq!( Bitset::<4>::none().to_vec_bool(), vec![false; 4] );
+    assert_eq!( Bitset::<4>::from([1,3]).to_vec_bool(), vec![true, false, true, false] );
+}
+
+#[test] fn from_vec_bool()
+{
+    assert_eq!( Bitset::<4>::from_vec_bool(&[]), Bitset::<4>::none() );
+    assert_eq!( Bitset::<4>::from_vec_bool(&[true, false, true]), Bitset::<4>::from([1,3]) );
+    assert_eq!( Bitset::<4>::from_vec_bool(&[false, true, false, true, true, true]), Bitset::<4>::from([2,4]) );
+}
+
+#[test] fn from_pairs()
+{
+    assert_eq!( Bitset::<8>::from_pairs([]), byteset![] );
+    assert_eq!( Bitset::<8>::from_pairs([(1, true), (2, false), (3, true)]), byteset![1,3] );
+    assert_eq!( Bitset::<8>::from_pairs([(0, true), (8, true), (9, true), (5, false)]), byteset![8] );
+    assert_eq!( Bitset::<8>::from_pairs([(4, true), (4, true)]), byteset![4] );
+}
+
+#[test] fn from_ranges()
+{
+    assert_eq!( Bitset::<9, u16>::from_ranges([(1,3), (7,9)]), Bitset::<9, u16>::from([1,2,3,7,8,9]) );
+    assert_eq!( Bitset::<9, u16>::from_ranges([(2,5), (4,6)]), Bitset::<9, u16>::from([2,3,4,5,6]) );
+    assert_eq!( Bitset::<9, u16>::from_ranges([(0,2), (8,99)]), Bitset::<9, u16>::from([1,2,8,9]) );
+    assert_eq!( Bitset::<9, u16>::from_ranges([(5,3), (10,12)]), Bitset::<9, u16>::none() );
+    assert_eq!( Bitset::<8>::from_ranges([(1,8)]), byteset![1;8] );
+}
+
+#[test] fn vec_bool_roundtrip()
+{
+    for z in 0..=u8::MAX {
+        let bitset = Bitset::<8>(z);
+        assert_eq!( Bitset::<8>::from_vec_bool(&bitset.to_vec_bool()), bitset );
+    }
+}
+
+#[test] fn byte_chunks()
+{
+    let bitset = Bitset::<16, u16>::from([1,2,8,9,16]);
+    assert_eq!( bitset.byte_chunks(), vec![byteset![1,2,8], byteset![1,8]] );
+    assert_eq!( Bitset::<16, u16>::none().byte_chunks(), vec![byteset![], byteset![]] );
+    assert_eq!( Bitset::<16, u16>::all().byte_chunks(), vec![byteset![1;8], byteset![1;8]] );
+
+    let partial = Bitset::<12, u16>(0b_1111_1001_0000_0001);
+    assert_eq!( partial.byte_chunks(), vec![byteset![1], byteset![1,4]] );
+}
+
+#[test] fn embed()
+{
+    let digits = Bitset::<9, u16>::from([1,5,9]);
+    assert_eq!( digits.embed::<81, u128>(), Bitset::<81, u128>::from([1,5,9]) );
+    assert_eq!( digits.embed::<9, u16>(), digits );
+
+    assert_eq!( Bitset::<9, u16>::all().embed::<81, u128>().members_asc(), (1..=9).collect::<Vec<_>>() );
+    assert_eq!( Bitset::<9, u16>::none().embed::<81, u128>(), Bitset::<81, u128>::none() );
+
+    assert_eq!( *Bitset::<4>(0b_1111_0001).embed::<8, u8>(), 0b_0000_0001 );
+}
+
+#[test] fn map_into()
+{
+    assert_eq!( byteset![].map_into::<100, u128>(|n| n * 10), Bitset::<100, u128>::none() );
+    assert_eq!( byteset![1,2].map_into::<100, u128>(|n| n * 10), Bitset::<100, u128>::from([10,20]) );
+    assert_eq!( byteset![1,2,8].map_into::<50, u64>(|n| n * 10), Bitset::<50, u64>::from([10,20]) );
+    assert_eq!( byteset![1,2,3].map_into::<4, u8>(|n| n - 1), Bitset::<4>::from([1,2]) );
+    assert_eq!( byteset![1;8].map_into::<16, u16>(|n| n * 2), Bitset::<16, u16>::from([2,4,6,8,10,12,14,16]) );
+    assert_eq!( byteset![1,2,3].map_into::<4, u8>(|_| 4), Bitset::<4>::from([4]) );
+}
+
+#[test] fn compress()
+{
+    assert_eq!( Bitset::<9, u16>::none().compress::<3>(), Bitset::<3, u16>::none() );
+    assert_eq!( Bitset::<9, u16>::from([2,5,8]).compress::<3>(), Bitset::<3, u16>::all() );
+    assert_eq!( Bitset::<9, u16>::from([2,5,8]).compress::<9>(), Bitset::<9, u16>::from([1,2,3]) );
+    assert_eq!( byteset![1;8].compress::<8>(), byteset![1;8] );
+}
+
+#[test] #[should_panic] fn compress_overflow()
+{
+    Bitset::<9, u16>::from([2,5,8]).compress::<2>();
+}
+
+#[test] fn decompress()
+{
+    let template = Bitset::<9, u16>::from([2,5,8]);
+
+    assert_eq!( Bitset::<3, u16>::none().decompress(&template), Bitset::<9, u16>::none() );
+    assert_eq!( Bitset::<3, u16>::from([1,3]).decompress(&template), Bitset::<9, u16>::from([2,8]) );
+    assert_eq!( Bitset::<4, u16>::from([2,4]).decompress(&template), Bitset::<9, u16>::from([5]) );
+
+    assert_eq!( template.compress::<3>().decompress(&template), template );
+}
+
+#[test] fn interleave()
+{
+    let none = Bitset::<2>::none();
+    let all = Bitset::<2>::all();
+
+    assert_eq!( *none.interleave::<4, u8>(none), 0b_0000 );
+    assert_eq!( *all.interleave::<4, u8>(none), 0b_0101 );
+    assert_eq!( *none.interleave::<4, u8>(all), 0b_1010 );
+    assert_eq!( *all.interleave::<4, u8>(all), 0b_1111 );
+
+    let left = Bitset::<8>::from([1,3,8]);
+    let right = Bitset::<8>::from([2,8]);
+    assert_eq!( left.interleave::<16, u16>(right), Bitset::<16, u16>::from([1,5,15,4,16]) );
+}
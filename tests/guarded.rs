@@ -0,0 +1,45 @@
+use natbitset::*;
+
+
+#[test] fn new()
+{
+    let guarded = Guarded::new(Bitset::<4>(0b_1010_0101));
+    assert_eq!( *guarded.get(), 0b_0000_0101 );
+    assert_eq!( guarded.get(), Bitset::<4>::from([1,3]) );
+
+    let guarded: Guarded<4> = Bitset::<4>(0b_1111_1111).into();
+    assert_eq!( *guarded.get(), 0b_0000_1111 );
+}
+
+#[test] fn update()
+{
+    let mut guarded = Guarded::new(Bitset::<4>::from([2,3,4]));
+    guarded.update(|bitset| **bitset <<= 2);
+    assert_eq!( *guarded.get(), 0b_0000_1000 );
+
+    let mut guarded = Guarded::new(Bitset::<4>::from([1,2]));
+    guarded.update(|bitset| **bitset = u8::MAX);
+    assert_eq!( *guarded.get(), 0b_0000_1111 );
+    assert_eq!( guarded.maximum(), Some(4) );
+}
+
+#[test] fn mutators()
+{
+    let mut guarded = Guarded::new(Bitset::<4>::none());
+
+    assert!( guarded.insert(3) );
+    assert!( !guarded.insert(7) );
+    assert!( !guarded.insert(-1) );
+    assert_eq!( *guarded.get(), 0b_0000_0100 );
+
+    assert!( guarded.remove(&3) );
+    assert!( !guarded.remove(&3) );
+    assert!( guarded.is_empty() );
+
+    guarded.update(|bitset| **bitset = u8::MAX);
+    guarded.retain(|n| n % 2 == 0);
+    assert_eq!( *guarded.get(), 0b_0000_1010 );
+
+    guarded.clear();
+    assert_eq!( *guarded.get(), 0 );
+}
@@ -8,3 +8,176 @@ use natbitset::*;
 #[test] #[should_panic] fn retain_nonempty_1() { byteset![].retain_nonempty_panicking(|_| true) }
 #[test] #[should_panic] fn retain_nonempty_2() { byteset![1].retain_nonempty_panicking(|_| false) }
 #[test] #[should_panic] fn retain_nonempty_3() { byteset![1;8].retain_nonempty_panicking(|_| false) }
+
+#[test] fn intersect_nonempty_int()
+{
+    let mut bitset = byteset![2,5,7];
+    assert!( bitset.intersect_nonempty_int(4).is_err() );
+    assert_eq!( bitset, byteset![2,5,7] );
+
+    assert!( bitset.intersect_nonempty_int(0).is_err() );
+    assert!( bitset.intersect_nonempty_int(-5).is_err() );
+    assert!( bitset.intersect_nonempty_int(99).is_err() );
+    assert_eq!( bitset, byteset![2,5,7] );
+
+    assert!( bitset.intersect_nonempty_int(7).is_ok() );
+    assert_eq!( bitset, byteset![7] );
+}
+
+#[test] fn retain_nonempty_count()
+{
+    let mut bitset = byteset![1,2,3,4,5];
+    assert_eq!( bitset.retain_nonempty(|n| n <= 3).unwrap(), 2 );
+    assert_eq!( bitset, byteset![1,2,3] );
+
+    assert_eq!( bitset.retain_nonempty(|_| true).unwrap(), 0 );
+    assert_eq!( bitset, byteset![1,2,3] );
+
+    assert_eq!( bitset.retain_nonempty(|n| n == 2).unwrap(), 2 );
+    assert_eq!( bitset, byteset![2] );
+
+    assert!( bitset.retain_nonempty(|_| false).is_err() );
+    assert_eq!( bitset, byteset![2] );
+}
+
+#[test] fn truncate_to_len()
+{
+    let mut bitset = byteset![1,2,3,4,5];
+    bitset.truncate_to_len(3);
+    assert_eq!( bitset, byteset![1,2,3] );
+
+    let mut bitset = byteset![2,4,6,8];
+    bitset.truncate_to_len(0);
+    assert_eq!( bitset, byteset![] );
+
+    let mut bitset = byteset![2,4,6,8];
+    bitset.truncate_to_len(4);
+    assert_eq!( bitset, byteset![2,4,6,8] );
+
+    let mut bitset = byteset![2,4,6,8];
+    bitset.truncate_to_len(99);
+    assert_eq!( bitset, byteset![2,4,6,8] );
+}
+
+#[test] fn toggle_all()
+{
+    let mut bitset = byteset![1,2,3];
+    assert_eq!( bitset.toggle_all(&byteset![3,4]), (byteset![4], byteset![3]) );
+    assert_eq!( bitset, byteset![1,2,4] );
+
+    let mut bitset = byteset![1,2,3];
+    assert_eq!( bitset.toggle_all(&byteset![]), (byteset![], byteset![]) );
+    assert_eq!( bitset, byteset![1,2,3] );
+
+    let mut bitset = byteset![1,2,3];
+    assert_eq!( bitset.toggle_all(&byteset![1;8]), (byteset![4;8], byteset![1,2,3]) );
+    assert_eq!( bitset, byteset![4;8] );
+}
+
+#[test] fn toggle_range()
+{
+    let mut bitset = byteset![1,2,3];
+    bitset.toggle_range(2, 5);
+    assert_eq!( bitset, byteset![1,4,5] );
+    bitset.toggle_range(2, 5);
+    assert_eq!( bitset, byteset![1,2,3] );
+
+    bitset.toggle_range(-4, 99);
+    assert_eq!( bitset, byteset![4;8] );
+    bitset.toggle_range(-4, 99);
+    assert_eq!( bitset, byteset![1,2,3] );
+
+    bitset.toggle_range(5, 2);
+    assert_eq!( bitset, byteset![1,2,3] );
+}
+
+#[test] fn retain_top_k_by()
+{
+    let mut bitset = byteset![1;8];
+    bitset.retain_top_k_by(3, |n| n as i64);
+    assert_eq!( bitset, byteset![6,7,8] );
+
+    let mut bitset = byteset![1;8];
+    bitset.retain_top_k_by(3, |n| -(n as i64));
+    assert_eq!( bitset, byteset![1,2,3] );
+
+    let mut bitset = byteset![1;8];
+    bitset.retain_top_k_by(2, |n| (n % 3) as i64);
+    assert_eq!( bitset, byteset![2,5] );
+
+    let mut bitset = byteset![2,4];
+    bitset.retain_top_k_by(2, |_| panic!("should not be evaluated"));
+    assert_eq!( bitset, byteset![2,4] );
+
+    let mut bitset = byteset![2,4];
+    bitset.retain_top_k_by(0, |n| n as i64);
+    assert_eq!( bitset, byteset![] );
+}
+
+#[test] fn allocate()
+{
+    let mut slots = Bitset::<4>::none();
+
+    for slot in 1..=4 {
+        assert_eq!( slots.allocate(), Some(slot) );
+    }
+    assert_eq!( slots.allocate(), None );
+    assert_eq!( slots, Bitset::<4>::all() );
+
+    assert!( slots.free(3) );
+    assert!( slots.free(2) );
+    assert_eq!( slots.allocate(), Some(2) );
+    assert_eq!( slots.allocate(), Some(3) );
+    assert_eq!( slots.allocate(), None );
+}
+
+#[test] fn free()
+{
+    let mut slots = byteset![1,3];
+
+    assert!( slots.free(1) );
+    assert!( !slots.free(1) );
+    assert!( !slots.free(2) );
+    assert!( !slots.free(0) );
+    assert!( !slots.free(9) );
+    assert_eq!( slots, byteset![3] );
+}
+
+#[test] fn fill_to_len()
+{
+    let mut bitset = byteset![2,4];
+    bitset.fill_to_len(4);
+    assert_eq!( bitset, byteset![1,2,3,4] );
+
+    let mut bitset = byteset![2,4];
+    bitset.fill_to_len(5);
+    assert_eq!( bitset, byteset![1,2,3,4,5] );
+
+    let mut bitset = byteset![2,4];
+    bitset.fill_to_len(1);
+    assert_eq!( bitset, byteset![2,4] );
+
+    let mut bitset = byteset![2,4];
+    bitset.fill_to_len(99);
+    assert_eq!( bitset, byteset![1;8] );
+}
+
+#[test] fn apply_mask()
+{
+    let cases = [
+        (MaskOp::Union,      byteset![1,2,3,5]),
+        (MaskOp::Intersect,  byteset![3]),
+        (MaskOp::Difference, byteset![1,2]),
+        (MaskOp::Toggle,     byteset![1,2,5]),
+    ];
+
+    for (op, expected) in cases {
+        let mut bitset = byteset![1,2,3];
+        bitset.apply_mask(0b_0001_0100, op);
+        assert_eq!( bitset, expected );
+    }
+
+    let mut bitset = Bitset::<4>(0b_1000_0001);
+    bitset.apply_mask(0b_0110_0000, MaskOp::Union);
+    assert_eq!( *bitset, 0b_0000_0001 );
+}
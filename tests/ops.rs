@@ -46,12 +46,28 @@ use natbitset::*;
     assert_eq!( bits, byteset![3,4] );
 }
 
+#[test] fn symmetric_difference_pure()
+{
+    assert_eq!( byteset![]    ^ byteset![1;8], byteset![1;8] );
+    assert_eq!( byteset![1;4] ^ byteset![1;4], byteset![] );
+    assert_eq!( byteset![1;3] ^ byteset![3,4], byteset![1,2,4] );
+}
+
+#[test] fn symmetric_difference_inplace()
+{
+    let mut bits = byteset![1;4];
+    bits ^= byteset![1,2,5];
+    assert_eq!( bits, byteset![3,4,5] );
+}
+
 #[test] fn add_pure()
 {
     assert_eq!( byteset![]    + 1, byteset![1] );
     assert_eq!( byteset![1;4] + 5, byteset![1;5] );
     assert_eq!( byteset![1;4] + 4, byteset![1;4] );
     assert_eq!( byteset![1;4] + 99, byteset![1;4] );
+    assert_eq!( byteset![1;4] + 0, byteset![1;4] );
+    assert_eq!( Bitset::<16, u16>::from([1]) + 17, Bitset::<16, u16>::from([1]) );
 }
 
 #[test] fn add_inplace()
@@ -74,3 +90,109 @@ use natbitset::*;
     bits -= 5;
     assert_eq!( bits, byteset![1;4] );
 }
+
+#[test] fn identities()
+{
+    let bits = byteset![2,3,5,7];
+
+    assert_eq!( bits | byteset![], bits );
+    assert_eq!( bits / byteset![], bits );
+    assert_eq!( bits & byteset![1;8], bits );
+    assert_eq!( bits & byteset![], byteset![] );
+
+    assert_eq!( byteset![] | byteset![], byteset![] );
+    assert_eq!( byteset![] / byteset![], byteset![] );
+    assert_eq!( byteset![] & byteset![1;8], byteset![] );
+
+    let wide = Bitset::<40, u64>::from([1,20,40]);
+    assert_eq!( wide & Bitset::<40, u64>(u64::MAX >> 24), wide );
+}
+
+#[test] fn identities_dirty()
+{
+    let dirty = Bitset::<4>(0b_1111_0101);
+
+    assert_eq!( *(dirty & Bitset::<4>::all()), 0b_0000_0101 );
+    assert_eq!( *(dirty | Bitset::<4>(0b_0001_0000)), 0b_1111_0101 );
+    assert_eq!( *(Bitset::<4>(0b_0001) | Bitset::<4>(0b_0001_0000)), 0b_0001_0001 );
+    assert_eq!( *(Bitset::<4>(0b_0001) & Bitset::<4>(0b_0001_0000)), 0 );
+    assert_eq!( *(dirty / Bitset::<4>(0b_0001_0000)), 0b_1110_0101 );
+
+    let mut bits = dirty;
+    bits &= Bitset::<4>::all();
+    assert_eq!( *bits, *(dirty & Bitset::<4>::all()) );
+
+    let mut bits = Bitset::<4>(0b_0001);
+    bits |= Bitset::<4>(0b_0001_0000);
+    assert_eq!( *bits, 0b_0001_0001 );
+
+    let mut bits = dirty;
+    bits /= Bitset::<4>(0b_0001_0000);
+    assert_eq!( *bits, 0b_1110_0101 );
+}
+
+#[test] fn identity_fast_paths()
+{
+    let dirty = Bitset::<4>(0b_1111_0101);
+
+    /* `| none()`, `/ none()` and `& none()` */
+    assert_eq!( *(dirty | Bitset::<4>::none()), 0b_1111_0101 );
+    assert_eq!( *(dirty / Bitset::<4>::none()), 0b_1111_0101 );
+    assert_eq!( *(dirty & Bitset::<4>::none()), 0 );
+
+    /* `& all()` gives the canonical bits of `self` */
+    assert_eq!( *(dirty & Bitset::<4>::all()), *dirty.canonical() );
+    assert_eq!( *(dirty & Bitset::<4>::all()), 0b_0000_0101 );
+    assert_eq!( *(Bitset::<4>(0b_0110) & Bitset::<4>::all()), 0b_0110 );
+
+    let mut bits = dirty;
+    bits |= Bitset::<4>::none();
+    assert_eq!( *bits, 0b_1111_0101 );
+
+    let mut bits = dirty;
+    bits /= Bitset::<4>::none();
+    assert_eq!( *bits, 0b_1111_0101 );
+
+    let mut bits = dirty;
+    bits &= Bitset::<4>::none();
+    assert_eq!( *bits, 0 );
+
+    let mut bits = dirty;
+    bits &= Bitset::<4>::all();
+    assert_eq!( *bits, 0b_0000_0101 );
+}
+
+#[test] fn const_ops()
+{
+    const FORBIDDEN: u16 = 0b_0000_0001_0000_0001;
+    const DIGITS: Bitset<9, u16> = Bitset::<9, u16>(0b_0000_0001_1111_1111).diff_const(FORBIDDEN);
+    const EVENS: Bitset<9, u16> = Bitset::<9, u16>(0).or_const(0b_0000_0000_1010_1010);
+    const BOTH: Bitset<9, u16> = DIGITS.and_const(EVENS.0);
+
+    assert_eq!( DIGITS, Bitset::<9, u16>::from_iter(2..=8) );
+    assert_eq!( EVENS, Bitset::<9, u16>::from([2,4,6,8]) );
+    assert_eq!( BOTH, EVENS );
+
+    assert_eq!( byteset![1;4].and_const(0b_0011_0000), byteset![] );
+    assert_eq!( byteset![1;4].or_const(0b_0011_0000), byteset![1;6] );
+    assert_eq!( byteset![1;4].diff_const(0b_0000_0011), byteset![3,4] );
+}
+
+#[test] fn combine()
+{
+    let pairs = [
+        (byteset![], byteset![]),
+        (byteset![1;4], byteset![5;8]),
+        (byteset![1;5], byteset![2,5]),
+        (byteset![1,3,5,7], byteset![2,3,6,7]),
+    ];
+
+    for (left, right) in pairs {
+        assert_eq!( left.combine(right, |a, b| a || b), left | right );
+        assert_eq!( left.combine(right, |a, b| a && b), left & right );
+        assert_eq!( left.combine(right, |a, b| a && !b), left / right );
+        assert_eq!( left.combine(right, |a, b| a != b), left ^ right );
+    }
+
+    assert_eq!( byteset![1,2].combine(byteset![2,3], |a, b| !a && !b), byteset![4;8] );
+}
@@ -9,6 +9,35 @@ use natbitset::*;
     assert!( !byteset![1;8].is_empty() );
 }
 
+#[test] fn fill_fraction()
+{
+    assert_eq!( byteset![].fill_fraction(), 0.0 );
+    assert_eq!( byteset![1,3,5,7].fill_fraction(), 0.5 );
+    assert_eq!( byteset![1;8].fill_fraction(), 1.0 );
+}
+
+#[test] fn remaining_capacity()
+{
+    assert_eq!( byteset![].remaining_capacity(), 8 );
+    assert_eq!( byteset![1,3,5,7].remaining_capacity(), 4 );
+    assert_eq!( byteset![1;8].remaining_capacity(), 0 );
+}
+
+#[test] fn summary()
+{
+    let summary = byteset![2,4,6].summary();
+    assert_eq!( summary.present, byteset![2,4,6] );
+    assert_eq!( summary.absent, byteset![1,3,5,7,8] );
+    assert_eq!( summary.len, 3 );
+    assert_eq!( summary.density, 0.375 );
+
+    let summary = Bitset::<4>(0b_1111_0001).summary();
+    assert_eq!( *summary.present, 0b_0000_0001 );
+    assert_eq!( *summary.absent, 0b_0000_1110 );
+    assert_eq!( summary.len, 1 );
+    assert_eq!( summary.density, 0.25 );
+}
+
 #[test] fn is_single()
 {
     assert!( byteset![1].is_single() );
@@ -18,6 +47,72 @@ use natbitset::*;
     assert!( !byteset![1;8].is_single() );
 }
 
+#[test] fn is_consecutive_from()
+{
+    assert!( byteset![].is_consecutive_from(1) );
+    assert!( byteset![].is_consecutive_from(0) );
+    assert!( byteset![].is_consecutive_from(99) );
+
+    assert!( byteset![1].is_consecutive_from(1) );
+    assert!( byteset![3,4,5].is_consecutive_from(3) );
+    assert!( byteset![1;8].is_consecutive_from(1) );
+    assert!( byteset![8].is_consecutive_from(8) );
+
+    assert!( !byteset![3,4,5].is_consecutive_from(2) );
+    assert!( !byteset![3,4,5].is_consecutive_from(4) );
+    assert!( !byteset![3,5,6].is_consecutive_from(3) );
+    assert!( !byteset![1;8].is_consecutive_from(0) );
+    assert!( !byteset![1,2].is_consecutive_from(usize::MAX) );
+    assert!( !byteset![1,2].is_consecutive_from(u64::MAX) );
+    assert!( !byteset![1].is_consecutive_from(-1) );
+}
+
+#[test] fn clamp_len()
+{
+    assert_eq!( byteset![].clamp_len(0).unwrap(), byteset![] );
+    assert_eq!( byteset![1,2,3].clamp_len(3).unwrap(), byteset![1,2,3] );
+    assert_eq!( byteset![1,2,3].clamp_len(4).unwrap(), byteset![1,2,3] );
+
+    let err = byteset![1;5].clamp_len(4).unwrap_err();
+    assert_eq!( (err.len, err.max), (5, 4) );
+
+    let err = byteset![8].clamp_len(0).unwrap_err();
+    assert_eq!( (err.len, err.max), (1, 0) );
+}
+
+#[test] fn debug_check_members_le()
+{
+    byteset![].debug_check_members_le(0);
+    byteset![1,2,3].debug_check_members_le(3);
+    byteset![1;8].debug_check_members_le(8);
+}
+
+#[cfg(debug_assertions)]
+#[test] #[should_panic] fn debug_check_members_le_exceed() { byteset![1,2,4].debug_check_members_le(3) }
+
+#[test] fn expect_subset_of()
+{
+    byteset![].expect_subset_of(&byteset![], "empty");
+    byteset![1,2].expect_subset_of(&byteset![1,2], "equal");
+    byteset![1,2].expect_subset_of(&byteset![1;8], "subset");
+}
+
+#[test] #[should_panic(expected = "not a subset: extra elements Bitset {4, 7}")]
+fn expect_subset_of_extra() { byteset![1,4,7].expect_subset_of(&byteset![1,2,3], "not a subset") }
+
+#[test] fn is_single_flag()
+{
+    assert!( byteset![1].is_single_flag() );
+    assert!( byteset![8].is_single_flag() );
+
+    assert!( !byteset![].is_single_flag() );
+    assert!( !byteset![1,2].is_single_flag() );
+    assert!( !byteset![1;8].is_single_flag() );
+
+    assert!( !Bitset::<4>(0b_0001_0000).is_single_flag() );
+    assert!( Bitset::<4>(0b_0001_0100).is_single_flag() );
+}
+
 #[test] fn is_full()
 {
     assert!( byteset![1;8].is_full() );
@@ -26,6 +121,19 @@ use natbitset::*;
     assert!( !byteset![1,2].is_full() );
 }
 
+#[test] fn has_out_of_range()
+{
+    assert!( !byteset![1;8].has(-1) );
+    assert!( !byteset![1;8].has(i64::MIN) );
+    assert!( !byteset![1;8].has(u64::MAX) );
+    assert!( !Bitset::<4>(0b_1111_1111).has(5) );
+    assert!( !Bitset::<4>(0b_1111_1111).contains(&8) );
+
+    let large = Bitset::<128, u128>::from([1,64,128]);
+    assert!( large.has(128) && large.has(64) && large.has(1) );
+    assert!( !large.has(127) && !large.has(129) );
+}
+
 #[test] fn has()
 {
     assert!( !byteset![].has(0) );
@@ -41,6 +149,261 @@ use natbitset::*;
     assert!( !byteset![1;8].has(9) );
 }
 
+#[test] fn has_usize()
+{
+    for bitset in [byteset![], byteset![1], byteset![2,4,7], byteset![1;8]] {
+        for n in 0..=9 {
+            assert_eq!( bitset.has_usize(n), bitset.has(n) );
+        }
+    }
+}
+
+#[test] fn check()
+{
+    assert_eq!( byteset![].check(&byteset![1,2]), (byteset![], byteset![1,2]) );
+    assert_eq!( byteset![1;8].check(&byteset![1,2]), (byteset![1,2], byteset![]) );
+    assert_eq!( byteset![1,2,3].check(&byteset![]), (byteset![], byteset![]) );
+    assert_eq!( byteset![1;4].check(&byteset![3;6]), (byteset![3,4], byteset![5,6]) );
+}
+
+#[test] fn diff()
+{
+    assert_eq!( byteset![].diff(&byteset![]), (byteset![], byteset![]) );
+    assert_eq!( byteset![1,2].diff(&byteset![1,2]), (byteset![], byteset![]) );
+    assert_eq!( byteset![].diff(&byteset![1;8]), (byteset![1;8], byteset![]) );
+    assert_eq!( byteset![1;8].diff(&byteset![]), (byteset![], byteset![1;8]) );
+    assert_eq!( byteset![1,2,3].diff(&byteset![2,3,4]), (byteset![4], byteset![1]) );
+
+    let states = [byteset![], byteset![1,2,3], byteset![2,4,6,8], byteset![5;8], byteset![1;8]];
+
+    for before in states {
+        for after in states {
+            let (added, removed) = before.diff(&after);
+            assert_eq!( (before / removed) | added, after );
+        }
+    }
+}
+
+#[test] fn intersect_iter()
+{
+    assert_eq!( byteset![1;8].intersect_iter([]), byteset![1;8] );
+    assert_eq!( byteset![1;8].intersect_iter([byteset![1,2,3], byteset![2,3,4]]), byteset![2,3] );
+    assert_eq!( byteset![1,2].intersect_iter([byteset![3,4], byteset![1,2]]), byteset![] );
+}
+
+#[test] fn intersect_iter_short_circuit()
+{
+    let sets = [byteset![1,2], byteset![3,4]].into_iter()
+        .chain(std::iter::from_fn(|| panic!("advanced past empty intersection")));
+
+    assert_eq!( byteset![1;8].intersect_iter(sets), byteset![] );
+    assert_eq!( byteset![].intersect_iter(std::iter::from_fn(|| panic!())), byteset![] );
+
+    let dirty = Bitset::<4>(0b_1111_0001);
+    assert_eq!( dirty.intersect_iter([Bitset::<4>(0b_1111_0010)]).0, 0 );
+    assert_eq!( Bitset::<4>(0b_1111_0000).intersect_iter(std::iter::from_fn(|| panic!())).0, 0 );
+}
+
+#[test] fn hamming_distance()
+{
+    assert_eq!( byteset![].hamming_distance(&byteset![]), 0 );
+    assert_eq!( byteset![1,4].hamming_distance(&byteset![1,4]), 0 );
+    assert_eq!( byteset![1,4].hamming_distance(&byteset![1,4,5]), 1 );
+    assert_eq!( byteset![1;4].hamming_distance(&byteset![5;8]), 8 );
+    assert_eq!( byteset![1,2,3].hamming_distance(&byteset![3,4]), 3 );
+
+    assert_eq!( Bitset::<4>(0b_1111_0000).hamming_distance(&Bitset::<4>(0)), 0 );
+}
+
+#[test] fn fits_with()
+{
+    assert!( byteset![].fits_with(&byteset![], 0) );
+    assert!( byteset![1,2].fits_with(&byteset![3], 3) );
+    assert!( byteset![1,2].fits_with(&byteset![3], 8) );
+    assert!( byteset![1;4].fits_with(&byteset![5;8], 8) );
+
+    assert!( !byteset![1,2].fits_with(&byteset![3], 2) );
+    assert!( !byteset![1;4].fits_with(&byteset![5;8], 7) );
+
+    assert!( !byteset![1,2].fits_with(&byteset![2], 3) );
+    assert!( !byteset![1,2].fits_with(&byteset![2], 8) );
+}
+
+#[test] fn set_eq()
+{
+    assert!( byteset![].set_eq(&byteset![]) );
+    assert!( byteset![1,3].set_eq(&byteset![1,3]) );
+    assert!( !byteset![1,3].set_eq(&byteset![1,4]) );
+
+    assert!( Bitset::<4>(0b_0000_0101).set_eq(&Bitset::<4>(0b_1000_0101)) );
+    assert!( Bitset::<4>(0b_1111_0000).set_eq(&Bitset::<4>(0b_0000_0000)) );
+    assert!( !Bitset::<4>(0b_1111_0001).set_eq(&Bitset::<4>(0b_1111_0000)) );
+}
+
+#[test] fn eq_ignoring()
+{
+    assert!( byteset![].eq_ignoring(&byteset![], &byteset![]) );
+    assert!( byteset![1,2].eq_ignoring(&byteset![1,2], &byteset![]) );
+    assert!( byteset![1,2,3].eq_ignoring(&byteset![1,2,4], &byteset![3,4]) );
+    assert!( byteset![1].eq_ignoring(&byteset![8], &byteset![1;8]) );
+
+    assert!( !byteset![1,2].eq_ignoring(&byteset![1,3], &byteset![]) );
+    assert!( !byteset![1,2,3].eq_ignoring(&byteset![1,2,4], &byteset![3]) );
+    assert!( !byteset![1,2,3].eq_ignoring(&byteset![2,3], &byteset![3,4]) );
+}
+
+#[test] fn zip_members()
+{
+    assert_eq!( byteset![].zip_members(byteset![1;8]).collect::<Vec<_>>(), vec![] );
+    assert_eq!( byteset![1,3,5].zip_members(byteset![2,4]).collect::<Vec<_>>(), vec![(1,2), (3,4)] );
+    assert_eq!( byteset![6,7,8].zip_members(byteset![1;8]).collect::<Vec<_>>(), vec![(6,1), (7,2), (8,3)] );
+}
+
+#[test] fn by_parity()
+{
+    assert_eq!( byteset![].by_parity(), (byteset![], byteset![]) );
+    assert_eq!( byteset![1,2,3,4].by_parity(), (byteset![1,3], byteset![2,4]) );
+    assert_eq!( byteset![1;8].by_parity(), (byteset![1,3,5,7], byteset![2,4,6,8]) );
+    assert_eq!( byteset![2,8].by_parity(), (byteset![], byteset![2,8]) );
+
+    let bitset = Bitset::<64, u64>::from([1,2,33,64]);
+    let (odds, evens) = bitset.by_parity();
+    assert_eq!( odds, Bitset::<64, u64>::from([1,33]) );
+    assert_eq!( evens, Bitset::<64, u64>::from([2,64]) );
+    assert_eq!( odds | evens, bitset );
+    assert!( odds.is_disjoint(&evens) );
+}
+
+#[test] fn slice()
+{
+    let bitset = byteset![1,3,5,7,8];
+
+    assert_eq!( bitset.slice(..), bitset );
+    assert_eq!( bitset.slice(1..=8), bitset );
+    assert_eq!( bitset.slice(0..99), bitset );
+
+    assert_eq!( bitset.slice(..=5), byteset![1,3,5] );
+    assert_eq!( bitset.slice(2..), byteset![3,5,7,8] );
+    assert_eq!( bitset.slice(2..5), byteset![3] );
+    assert_eq!( bitset.slice(8..=8), byteset![8] );
+
+    assert_eq!( bitset.slice(5..5), byteset![] );
+    assert_eq!( bitset.slice(..1), byteset![] );
+    assert_eq!( bitset.slice(9..), byteset![] );
+}
+
+#[test] fn shift_left()
+{
+    assert_eq!( byteset![].shift_left(1), byteset![] );
+    assert_eq!( byteset![1,4,6].shift_left(0), byteset![1,4,6] );
+    assert_eq!( byteset![1,4,6].shift_left(2), byteset![3,6,8] );
+    assert_eq!( byteset![1,4,6].shift_left(3), byteset![4,7] );
+    assert_eq!( byteset![1;8].shift_left(7), byteset![8] );
+    assert_eq!( byteset![1;8].shift_left(8), byteset![] );
+    assert_eq!( byteset![1;8].shift_left(99), byteset![] );
+
+    assert_eq!( Bitset::<4>::all().shift_left(2), Bitset::<4>::from([3,4]) );
+    assert_eq!( Bitset::<100, u128>::from([1]).shift_left(100), Bitset::<100, u128>::none() );
+}
+
+#[test] fn shift_right()
+{
+    assert_eq!( byteset![].shift_right(1), byteset![] );
+    assert_eq!( byteset![1,4,6].shift_right(0), byteset![1,4,6] );
+    assert_eq!( byteset![1,4,6].shift_right(2), byteset![2,4] );
+    assert_eq!( byteset![1;8].shift_right(7), byteset![1] );
+    assert_eq!( byteset![1;8].shift_right(8), byteset![] );
+    assert_eq!( byteset![1;8].shift_right(99), byteset![] );
+
+    assert_eq!( Bitset::<4>(0b_1111_1111).shift_right(2), Bitset::<4>::from([1,2]) );
+}
+
+#[test] fn rotate_left()
+{
+    assert_eq!( byteset![].rotate_left(3), byteset![] );
+    assert_eq!( byteset![1,8].rotate_left(0), byteset![1,8] );
+    assert_eq!( byteset![1,8].rotate_left(1), byteset![1,2] );
+    assert_eq!( byteset![1,8].rotate_left(7), byteset![7,8] );
+    assert_eq!( byteset![1,8].rotate_left(8), byteset![1,8] );
+    assert_eq!( byteset![1,8].rotate_left(10), byteset![2,3] );
+    assert_eq!( byteset![1;8].rotate_left(5), byteset![1;8] );
+
+    assert_eq!( Bitset::<5>::from([1,5]).rotate_left(1), Bitset::<5>::from([1,2]) );
+    assert_eq!( Bitset::<5>::from([4,5]).rotate_left(3), Bitset::<5>::from([2,3]) );
+}
+
+#[test] fn rotate_right()
+{
+    assert_eq!( byteset![].rotate_right(3), byteset![] );
+    assert_eq!( byteset![1,8].rotate_right(0), byteset![1,8] );
+    assert_eq!( byteset![1,8].rotate_right(1), byteset![7,8] );
+    assert_eq!( byteset![1,8].rotate_right(7), byteset![1,2] );
+    assert_eq!( byteset![1,8].rotate_right(8), byteset![1,8] );
+
+    assert_eq!( Bitset::<5>::from([1,2]).rotate_right(1), Bitset::<5>::from([1,5]) );
+
+    for by in 0..20 {
+        assert_eq!( byteset![2,3,7].rotate_left(by).rotate_right(by), byteset![2,3,7] );
+    }
+}
+
+#[test] fn rotate()
+{
+    assert_eq!( byteset![1,8].rotate(0), byteset![1,8] );
+    assert_eq!( byteset![1,8].rotate(1), byteset![1,2] );
+    assert_eq!( byteset![1,8].rotate(-1), byteset![7,8] );
+    assert_eq!( byteset![1,8].rotate(-9), byteset![7,8] );
+    assert_eq!( byteset![1,8].rotate(isize::MIN), byteset![1,8].rotate_right(isize::MIN.unsigned_abs()) );
+
+    for by in -20..20 {
+        assert_eq!( byteset![2,3,7].rotate(by).rotate(-by), byteset![2,3,7] );
+    }
+}
+
+#[test] fn rotate_preserves_len()
+{
+    for z in 0..=u8::MAX {
+        let bitset = Bitset::<8>(z);
+
+        for by in 0..=17 {
+            assert_eq!( bitset.rotate_left(by).len(), bitset.len() );
+            assert_eq!( bitset.rotate_right(by).len(), bitset.len() );
+        }
+    }
+
+    for z in 0..32 {
+        let bitset = Bitset::<5>(z);
+
+        for by in -11..=11 {
+            assert_eq!( bitset.rotate(by).len(), bitset.len() );
+        }
+    }
+
+    let wide = Bitset::<64, u64>::from([1,2,32,63,64]);
+    for by in [1, 31, 32, 63, 64, 65, 1000] {
+        assert_eq!( wide.rotate_left(by).len(), 5 );
+        assert_eq!( wide.rotate_right(by).len(), 5 );
+    }
+}
+
+#[test] fn reversed()
+{
+    assert_eq!( byteset![].reversed(), byteset![] );
+    assert_eq!( byteset![1,2,6].reversed(), byteset![3,7,8] );
+    assert_eq!( byteset![1;8].reversed(), byteset![1;8] );
+
+    assert_eq!( Bitset::<5>::from([1,2]).reversed(), Bitset::<5>::from([4,5]) );
+    assert_eq!( Bitset::<5>::from([3]).reversed(), Bitset::<5>::from([3]) );
+    assert_eq!( Bitset::<5>(0b_1110_0001).reversed(), Bitset::<5>::from([5]) );
+
+    assert_eq!( Bitset::<9, u16>::from([1,5,7]).reversed(), Bitset::<9, u16>::from([3,5,9]) );
+    assert_eq!( Bitset::<64, u64>::from([1,40]).reversed(), Bitset::<64, u64>::from([25,64]) );
+
+    for z in 0..=u8::MAX {
+        assert_eq!( Bitset::<8>(z).reversed().reversed(), Bitset::<8>(z) );
+    }
+}
+
 #[test] fn members()
 {
     assert_eq!( byteset![].members(), std::collections::HashSet::new() );
@@ -48,6 +411,118 @@ use natbitset::*;
     assert_eq!( byteset![1;8].members(), (1..=8).rev().collect() );
 }
 
+#[test] fn count_matching()
+{
+    assert_eq!( byteset![].count_matching(|_| true), 0 );
+    assert_eq!( byteset![1,2,3,4].count_matching(|n| n % 2 == 0), 2 );
+    assert_eq!( byteset![1,2,3,4].count_matching(|_| false), 0 );
+    assert_eq!( byteset![1;8].count_matching(|_| true), 8 );
+}
+
+#[test] fn try_fold_members()
+{
+    assert_eq!( byteset![].try_fold_members(0, |acc, n| Ok::<_, ()>(acc + n)), Ok(0) );
+    assert_eq!( byteset![1;8].try_fold_members(0, |acc, n| Ok::<_, ()>(acc + n)), Ok(36) );
+
+    let mut visited = vec![];
+    let result = byteset![1,3,5,7].try_fold_members(0, |acc, n| {
+        visited.push(n);
+        if n < 5 { Err(acc) } else { Ok(acc + n) }
+    });
+    assert_eq!( result, Err(12) );
+    assert_eq!( visited, vec![7,5,3] );
+}
+
+#[test] fn find_member()
+{
+    assert_eq!( byteset![].find_member(|_| true), None );
+    assert_eq!( byteset![1;8].find_member(|_| false), None );
+    assert_eq!( byteset![1;8].find_member(|n| n > 4), Some(5) );
+    assert_eq!( byteset![1,4,6].find_member(|n| n % 2 == 0), Some(4) );
+
+    let mut visited = vec![];
+    byteset![2,3,5,7].find_member(|n| { visited.push(n); n == 5 });
+    assert_eq!( visited, vec![2,3,5] );
+}
+
+#[test] fn find_map_member()
+{
+    assert_eq!( byteset![].find_map_member(Some), None );
+    assert_eq!( byteset![3,8].find_map_member(Some), Some(3) );
+    assert_eq!( byteset![1,4,6].find_map_member(|n| (n % 2 == 0).then_some(n * 10)), Some(40) );
+    assert_eq!( byteset![1,3,5].find_map_member(|n| (n % 2 == 0).then_some(n * 10)), None );
+}
+
+#[test] fn cardinality_histogram()
+{
+    assert_eq!( Bitset::<4>::cardinality_histogram(&[]), vec![0; 5] );
+
+    let sets = [
+        Bitset::<4>::none(),
+        Bitset::<4>::from([1]),
+        Bitset::<4>::from([4]),
+        Bitset::<4>::from([2,3]),
+        Bitset::<4>::all(),
+    ];
+    assert_eq!( Bitset::cardinality_histogram(&sets), vec![1, 2, 1, 0, 1] );
+}
+
+#[test] fn is_arithmetic_progression()
+{
+    assert_eq!( byteset![].is_arithmetic_progression(), None );
+    assert_eq!( byteset![5].is_arithmetic_progression(), Some(0) );
+    assert_eq!( byteset![1,8].is_arithmetic_progression(), Some(7) );
+
+    assert_eq!( byteset![2,4,6].is_arithmetic_progression(), Some(2) );
+    assert_eq!( byteset![1,4,7].is_arithmetic_progression(), Some(3) );
+    assert_eq!( byteset![1;8].is_arithmetic_progression(), Some(1) );
+
+    assert_eq!( byteset![1,2,4].is_arithmetic_progression(), None );
+    assert_eq!( byteset![2,4,6,7].is_arithmetic_progression(), None );
+}
+
+#[test] fn occurrence_counts()
+{
+    assert_eq!( Bitset::<4>::occurrence_counts(&[]), vec![0; 4] );
+
+    let sets = [
+        Bitset::<4>::from([1,2]),
+        Bitset::<4>::from([2,3]),
+        Bitset::<4>::from([2,3,4]),
+    ];
+    assert_eq!( Bitset::occurrence_counts(&sets), vec![1, 3, 2, 1] );
+
+    let dirty = [Bitset::<4>(0b_1111_0001)];
+    assert_eq!( Bitset::occurrence_counts(&dirty), vec![1, 0, 0, 0] );
+}
+
+#[test] fn dense_ranks()
+{
+    assert_eq!( byteset![].dense_ranks(), vec![] );
+    assert_eq!( byteset![3,5,8].dense_ranks(), vec![(3,1), (5,2), (8,3)] );
+    assert_eq!( byteset![1;3].dense_ranks(), vec![(1,1), (2,2), (3,3)] );
+}
+
+#[test] fn positions_of()
+{
+    assert_eq!( byteset![].positions_of(&byteset![1;8]), vec![] );
+    assert_eq!( byteset![1;8].positions_of(&byteset![]), vec![] );
+    assert_eq!( byteset![1;8].positions_of(&byteset![1,8]), vec![0, 7] );
+    assert_eq!( byteset![2,3,5,7].positions_of(&byteset![1,2,5]), vec![0, 2] );
+    assert_eq!( byteset![4,6,8].positions_of(&byteset![4;8]), vec![0, 1, 2] );
+}
+
+#[test] fn minimum()
+{
+    assert_eq!( byteset![].minimum(), None );
+    assert_eq!( byteset![1].minimum(), Some(1) );
+    assert_eq!( byteset![8].minimum(), Some(8) );
+    assert_eq!( byteset![3,5,8].minimum(), Some(3) );
+    assert_eq!( byteset![1;8].minimum(), Some(1) );
+    assert_eq!( Bitset::<4>(0b_1111_0000).minimum(), None );
+    assert_eq!( Bitset::<128, u128>::from([100,128]).minimum(), Some(100) );
+}
+
 #[test] fn maximum()
 {
     assert_eq!( byteset![].maximum(), None );
@@ -57,6 +532,89 @@ use natbitset::*;
     assert_eq!( byteset![1,2,4,7,8].maximum(), Some(8) );
 }
 
+#[test] fn leading_gap()
+{
+    assert_eq!( byteset![].leading_gap(), 8 );
+    assert_eq!( byteset![1].leading_gap(), 0 );
+    assert_eq!( byteset![3,4,6].leading_gap(), 2 );
+    assert_eq!( byteset![8].leading_gap(), 7 );
+    assert_eq!( Bitset::<4>(0b_1111_0000).leading_gap(), 4 );
+}
+
+#[test] fn trailing_gap()
+{
+    assert_eq!( byteset![].trailing_gap(), 8 );
+    assert_eq!( byteset![8].trailing_gap(), 0 );
+    assert_eq!( byteset![3,4,6].trailing_gap(), 2 );
+    assert_eq!( byteset![1].trailing_gap(), 7 );
+    assert_eq!( Bitset::<4>(0b_1111_0001).trailing_gap(), 3 );
+}
+
+#[test] fn nearest_member()
+{
+    assert_eq!( byteset![].nearest_member(4), None );
+
+    let bitset = byteset![2,6,7];
+
+    assert_eq!( bitset.nearest_member(2), Some(2) );
+    assert_eq!( bitset.nearest_member(6), Some(6) );
+    assert_eq!( bitset.nearest_member(7), Some(7) );
+
+    assert_eq!( bitset.nearest_member(3), Some(2) );
+    assert_eq!( bitset.nearest_member(4), Some(2) );
+    assert_eq!( bitset.nearest_member(5), Some(6) );
+
+    assert_eq!( bitset.nearest_member(1), Some(2) );
+    assert_eq!( bitset.nearest_member(0), Some(2) );
+    assert_eq!( bitset.nearest_member(-5), Some(2) );
+    assert_eq!( bitset.nearest_member(8), Some(7) );
+    assert_eq!( bitset.nearest_member(99), Some(7) );
+    assert_eq!( bitset.nearest_member(u128::MAX), Some(7) );
+}
+
+#[test] fn highest_below()
+{
+    assert_eq!( byteset![].highest_below(5), None );
+
+    let bitset = byteset![2,5,7];
+    assert_eq!( bitset.highest_below(5), Some(2) );
+    assert_eq!( bitset.highest_below(6), Some(5) );
+    assert_eq!( bitset.highest_below(8), Some(7) );
+    assert_eq!( bitset.highest_below(99), Some(7) );
+    assert_eq!( bitset.highest_below(2), None );
+    assert_eq!( bitset.highest_below(1), None );
+    assert_eq!( bitset.highest_below(-1), None );
+
+    assert_eq!( byteset![1;8].highest_below(1), None );
+}
+
+#[test] fn lowest_above()
+{
+    assert_eq!( byteset![].lowest_above(5), None );
+
+    let bitset = byteset![2,5,7];
+    assert_eq!( bitset.lowest_above(5), Some(7) );
+    assert_eq!( bitset.lowest_above(4), Some(5) );
+    assert_eq!( bitset.lowest_above(1), Some(2) );
+    assert_eq!( bitset.lowest_above(-1), Some(2) );
+    assert_eq!( bitset.lowest_above(7), None );
+    assert_eq!( bitset.lowest_above(99), None );
+
+    assert_eq!( byteset![1;8].lowest_above(8), None );
+}
+
+#[test] fn to_range()
+{
+    assert_eq!( byteset![].to_range(), None );
+
+    assert_eq!( byteset![1].to_range(), Some(1..=1) );
+    assert_eq!( byteset![3,4,5].to_range(), Some(3..=5) );
+    assert_eq!( byteset![1;8].to_range(), Some(1..=8) );
+
+    assert_eq!( byteset![3,5].to_range(), None );
+    assert_eq!( byteset![1,2,3,8].to_range(), None );
+}
+
 #[test] fn single()
 {
     assert_eq!( byteset![1].only(), Some(1) );
@@ -66,3 +624,16 @@ use natbitset::*;
     assert_eq!( byteset![1,2].only(), None );
     assert_eq!( byteset![1;8].only(), None );
 }
+
+#[test] fn flag_value()
+{
+    assert_eq!( byteset![1].flag_value(), Some(0b_0000_0001) );
+    assert_eq!( byteset![8].flag_value(), Some(0b_1000_0000) );
+
+    assert_eq!( byteset![].flag_value(), None );
+    assert_eq!( byteset![1,2].flag_value(), None );
+    assert_eq!( byteset![1;8].flag_value(), None );
+
+    assert_eq!( Bitset::<4>(0b_0001_0000).flag_value(), None );
+    assert_eq!( Bitset::<4>(0b_0001_0100).flag_value(), Some(0b_0000_0100) );
+}
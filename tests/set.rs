@@ -9,6 +9,41 @@ use natbitset::*;
     assert_eq!( byteset![1;8].len(), 8 );
 }
 
+#[test] fn len_large()
+{
+    assert_eq!( Bitset::<128, u128>::none().len(), 0 );
+    assert_eq!( Bitset::<128, u128>::all().len(), 128 );
+    assert_eq!( Bitset::<128, u128>::from([1,64,65,128]).len(), 4 );
+    assert_eq!( Bitset::<100, u128>(u128::MAX).len(), 100 );
+}
+
+#[test] fn len_is_odd()
+{
+    assert!( !byteset![].len_is_odd() );
+    assert!( byteset![1].len_is_odd() );
+    assert!( !byteset![1,8].len_is_odd() );
+    assert!( byteset![1;7].len_is_odd() );
+    assert!( !byteset![1;8].len_is_odd() );
+}
+
+#[test] fn len_at_least()
+{
+    assert!( byteset![].len_at_least(0) );
+    assert!( !byteset![].len_at_least(1) );
+    assert!( byteset![2,4,6].len_at_least(2) );
+    assert!( byteset![2,4,6].len_at_least(3) );
+    assert!( !byteset![2,4,6].len_at_least(4) );
+}
+
+#[test] fn len_at_most()
+{
+    assert!( byteset![].len_at_most(0) );
+    assert!( !byteset![2,4,6].len_at_most(2) );
+    assert!( byteset![2,4,6].len_at_most(3) );
+    assert!( byteset![2,4,6].len_at_most(4) );
+    assert!( byteset![1;8].len_at_most(99) );
+}
+
 #[test] fn contains()
 {
     assert!( !byteset![].contains(&0) );
@@ -24,6 +59,20 @@ use natbitset::*;
     assert!( !byteset![1;8].contains(&9) );
 }
 
+#[test] fn validate()
+{
+    assert_eq!( Bitset::<8>::validate(1), Some(1) );
+    assert_eq!( Bitset::<8>::validate(8u64), Some(8) );
+
+    assert_eq!( Bitset::<8>::validate(0), None );
+    assert_eq!( Bitset::<8>::validate(9), None );
+    assert_eq!( Bitset::<8>::validate(-1), None );
+    assert_eq!( Bitset::<8>::validate(i64::MIN), None );
+
+    assert_eq!( Bitset::<16, u16>::validate(16), Some(16) );
+    assert_eq!( Bitset::<16, u16>::validate(17), None );
+}
+
 #[test] fn insert()
 {
     let mut bitset = byteset![];
@@ -35,6 +84,79 @@ use natbitset::*;
     assert_eq!( bitset, byteset![1] );
 }
 
+#[test] fn insert_usize()
+{
+    let mut bitset = byteset![];
+
+    assert!( bitset.insert_usize(1) );
+    assert_eq!( bitset, byteset![1] );
+
+    assert!( !bitset.insert_usize(1) );
+    assert!( !bitset.insert_usize(0) );
+    assert!( !bitset.insert_usize(9) );
+    assert_eq!( bitset, byteset![1] );
+}
+
+#[test] fn try_insert()
+{
+    let mut bitset = byteset![];
+
+    assert_eq!( bitset.try_insert(1), Ok(true) );
+    assert_eq!( bitset.try_insert(1), Ok(false) );
+    assert_eq!( bitset.try_insert(0), Ok(false) );
+    assert_eq!( bitset.try_insert(9), Ok(false) );
+    assert!( bitset.try_insert(-1).is_err() );
+    assert_eq!( bitset, byteset![1] );
+}
+
+#[test] fn try_remove()
+{
+    let mut bitset = byteset![1,2];
+
+    assert_eq!( bitset.try_remove(&1), Ok(true) );
+    assert_eq!( bitset.try_remove(&1), Ok(false) );
+    assert_eq!( bitset.try_remove(&0), Ok(false) );
+    assert_eq!( bitset.try_remove(&9), Ok(false) );
+    assert!( bitset.try_remove(&-1).is_err() );
+    assert_eq!( bitset, byteset![2] );
+}
+
+#[test] fn remove_iter()
+{
+    let mut bitset = byteset![1,2,3,4];
+    assert_eq!( bitset.remove_iter([2,4,99]), 2 );
+    assert_eq!( bitset, byteset![1,3] );
+
+    assert_eq!( bitset.remove_iter([2,2,4]), 0 );
+    assert_eq!( bitset, byteset![1,3] );
+
+    assert_eq!( bitset.remove_iter(vec![3,3,1]), 2 );
+    assert_eq!( bitset, byteset![] );
+}
+
+#[test] fn difference_update_iter()
+{
+    let mut bitset = byteset![1;8];
+    bitset.difference_update_iter("2,4,x,8,99".split(',').filter_map(|s| s.parse::<i32>().ok()));
+    assert_eq!( bitset, byteset![1,3,5,6,7] );
+
+    bitset.difference_update_iter(Vec::<u8>::new());
+    assert_eq!( bitset, byteset![1,3,5,6,7] );
+}
+
+#[test] fn clear_if()
+{
+    let mut bitset = byteset![1;8];
+    assert!( !bitset.clear_if(false) );
+    assert_eq!( bitset, byteset![1;8] );
+
+    assert!( bitset.clear_if(true) );
+    assert_eq!( bitset, byteset![] );
+
+    assert!( !bitset.clear_if(true) );
+    assert_eq!( bitset, byteset![] );
+}
+
 #[test] fn is_subset()
 {
     assert!( byteset![].is_subset(&byteset![]) );
@@ -59,6 +181,47 @@ use natbitset::*;
     assert!( byteset![1;8].is_superset(&byteset![1;8]) );
 }
 
+#[test] fn difference_subset_of()
+{
+    assert!( byteset![].difference_subset_of(&byteset![], &byteset![]) );
+    assert!( byteset![1;8].difference_subset_of(&byteset![1;8], &byteset![]) );
+    assert!( byteset![1;8].difference_subset_of(&byteset![1;4], &byteset![5;8]) );
+    assert!( byteset![1,2,3].difference_subset_of(&byteset![3], &byteset![1,2]) );
+
+    assert!( !byteset![1,2,3].difference_subset_of(&byteset![1], &byteset![1,2]) );
+    assert!( !byteset![1;8].difference_subset_of(&byteset![1;4], &byteset![5;7]) );
+
+    for (a, b, c) in [(0b_1010_1010, 0b_1000_0010, 0b_0010_1000), (0b_1111_0000, 0b_0011_0000, 0b_1000_0000), (0b_0101, 0b_0001, 0b_1100)] {
+        let (a, b, c) = (Bitset::<8>(a), Bitset::<8>(b), Bitset::<8>(c));
+        assert_eq!( a.difference_subset_of(&b, &c), (a / b).is_subset(&c) );
+    }
+}
+
+#[test] fn union_tagged()
+{
+    assert_eq!( byteset![1,2,3].union_tagged(&byteset![3,4]), (byteset![1,2], byteset![3], byteset![4]) );
+    assert_eq!( byteset![].union_tagged(&byteset![1,2]), (byteset![], byteset![], byteset![1,2]) );
+    assert_eq!( byteset![1;8].union_tagged(&byteset![1;8]), (byteset![], byteset![1;8], byteset![]) );
+
+    for (left, right) in [(byteset![1;5], byteset![4;8]), (byteset![2,4,6,8], byteset![1,2,3]), (byteset![1,7], byteset![2,8])] {
+        let (only_left, both, only_right) = left.union_tagged(&right);
+
+        assert!( only_left.is_disjoint(&both) );
+        assert!( only_left.is_disjoint(&only_right) );
+        assert!( both.is_disjoint(&only_right) );
+        assert_eq!( only_left | both | only_right, left | right );
+    }
+}
+
+#[test] fn common_count()
+{
+    assert_eq!( byteset![].common_count(&byteset![]), 0 );
+    assert_eq!( byteset![1,2,4].common_count(&byteset![2,3,4]), 2 );
+    assert_eq!( byteset![1;4].common_count(&byteset![5;8]), 0 );
+    assert_eq!( byteset![1;8].common_count(&byteset![1;8]), 8 );
+    assert_eq!( Bitset::<4>(0b_1111_0011).common_count(&Bitset::<4>(0b_1111_0110)), 1 );
+}
+
 #[test] fn is_disjoint()
 {
     assert!( byteset![1].is_disjoint(&byteset![2]) );
@@ -88,3 +251,64 @@ use natbitset::*;
     bitset.retain(|n| n % 2 == 0);
     assert_eq!( bitset, byteset![2,4,6,8] );
 }
+
+#[test] fn retain_order()
+{
+    let mut visited = vec![];
+    byteset![1,4,6].retain(|n| { visited.push(n); true });
+    assert_eq!( visited, vec![6,4,1] );
+
+    let mut visited = vec![];
+    byteset![1,4,6].retain_asc(|n| { visited.push(n); true });
+    assert_eq!( visited, vec![1,4,6] );
+}
+
+#[test] fn try_retain()
+{
+    let mut bitset = byteset![1;8];
+    assert_eq!( bitset.try_retain(|n| Ok::<_, ()>(n % 2 == 0)), Ok(()) );
+    assert_eq!( bitset, byteset![2,4,6,8] );
+
+    let mut visited = vec![];
+    let mut bitset = byteset![1;8];
+    let result = bitset.try_retain(|n| {
+        visited.push(n);
+        if n == 5 { Err("bad candidate") } else { Ok(n > 6) }
+    });
+    assert_eq!( result, Err("bad candidate") );
+    assert_eq!( visited, vec![8,7,6,5] );
+    assert_eq!( bitset, byteset![1;8] );
+}
+
+#[test] fn retain_asc()
+{
+    let mut bitset = byteset![1;8];
+    bitset.retain_asc(|n| n % 2 == 0);
+    assert_eq!( bitset, byteset![2,4,6,8] );
+
+    let mut seen = 0;
+    let mut bitset = byteset![1;8];
+    bitset.retain_asc(|_| { seen += 1; seen <= 3 });
+    assert_eq!( bitset, byteset![1,2,3] );
+}
+
+#[test] fn drain_filter()
+{
+    let mut bitset = byteset![1;8];
+    assert_eq!( bitset.drain_filter(|n| n % 2 == 0), byteset![2,4,6,8] );
+    assert_eq!( bitset, byteset![1,3,5,7] );
+
+    let mut bitset = byteset![1;8];
+    assert_eq!( bitset.drain_filter(|_| false), byteset![] );
+    assert_eq!( bitset, byteset![1;8] );
+
+    let mut bitset = byteset![2,3,5,7];
+    assert_eq!( bitset.drain_filter(|_| true), byteset![2,3,5,7] );
+    assert_eq!( bitset, byteset![] );
+
+    let original = byteset![1,2,4,5,8];
+    let mut bitset = original;
+    let drained = bitset.drain_filter(|n| n > 3);
+    assert_eq!( drained | bitset, original );
+    assert!( drained.is_disjoint(&bitset) );
+}
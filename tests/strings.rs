@@ -0,0 +1,71 @@
+use natbitset::*;
+
+
+#[test] fn from_binary_str()
+{
+    assert_eq!( Bitset::<6>::from_binary_str("").unwrap(), Bitset::<6>::none() );
+    assert_eq!( Bitset::<6>::from_binary_str("1").unwrap(), Bitset::<6>::from([1]) );
+    assert_eq!( Bitset::<6>::from_binary_str("010110").unwrap(), Bitset::<6>::from([2,3,5]) );
+    assert_eq!( Bitset::<8>::from_binary_str("10110").unwrap(), byteset![2,3,5] );
+}
+
+#[test] fn from_binary_str_invalid()
+{
+    assert!( Bitset::<6>::from_binary_str("0101102").is_err() );
+    assert!( Bitset::<6>::from_binary_str("01_01").is_err() );
+    assert!( Bitset::<6>::from_binary_str("0b0101").is_err() );
+}
+
+#[test] fn from_binary_str_exceed()
+{
+    assert!( Bitset::<4>::from_binary_str("10000").is_err() );
+    assert!( Bitset::<4>::from_binary_str("00000").is_err() );
+}
+
+#[test] fn to_binary_str()
+{
+    assert_eq!( Bitset::<6>::none().to_binary_str(), "000000" );
+    assert_eq!( Bitset::<6>::from([2,3,5]).to_binary_str(), "010110" );
+    assert_eq!( byteset![1;8].to_binary_str(), "11111111" );
+}
+
+#[test] fn binary_str_roundtrip()
+{
+    for z in 0..=u8::MAX {
+        let bitset = Bitset::<8>(z);
+        assert_eq!( Bitset::<8>::from_binary_str(&bitset.to_binary_str()).unwrap(), bitset );
+    }
+}
+
+#[test] fn from_digits_str()
+{
+    assert_eq!( Bitset::<8>::from_digits_str("").unwrap(), byteset![] );
+    assert_eq!( Bitset::<8>::from_digits_str("137").unwrap(), byteset![1,3,7] );
+    assert_eq!( Bitset::<8>::from_digits_str(" 13 7 ").unwrap(), byteset![1,3,7] );
+    assert_eq!( Bitset::<8>::from_digits_str("7311").unwrap(), byteset![1,3,7] );
+    assert_eq!( Bitset::<9, u16>::from_digits_str("19").unwrap(), Bitset::<9, u16>::from([1,9]) );
+}
+
+#[test] fn from_digits_str_invalid()
+{
+    assert!( Bitset::<8>::from_digits_str("0").is_err() );
+    assert!( Bitset::<8>::from_digits_str("9").is_err() );
+    assert!( Bitset::<8>::from_digits_str("1,3").is_err() );
+    assert!( Bitset::<8>::from_digits_str("1x").is_err() );
+}
+
+#[test] fn join()
+{
+    assert_eq!( byteset![].join("-"), "" );
+    assert_eq!( byteset![4].join("-"), "4" );
+    assert_eq!( byteset![1,3,7].join("-"), "1-3-7" );
+    assert_eq!( byteset![1,3,7].join(", "), "1, 3, 7" );
+    assert_eq!( byteset![1,3,7].join(""), "137" );
+}
+
+#[test] fn to_ascii_bar()
+{
+    assert_eq!( Bitset::<4>::none().to_ascii_bar('#', '.'), "...." );
+    assert_eq!( Bitset::<4>::from([1,3]).to_ascii_bar('#', '.'), "#.#." );
+    assert_eq!( byteset![1;8].to_ascii_bar('█', ' '), "████████" );
+}
@@ -9,8 +9,193 @@ use natbitset::*;
     assert_eq!( *bitset, 0 );
 }
 
+#[test] fn canonical_eq_hash()
+{
+    use std::collections::HashMap;
+
+    let clean = Bitset::<4>(0b_0000_0101);
+    let dirty = Bitset::<4>(0b_1010_0101);
+    assert_eq!( *dirty.canonical(), 0b_0000_0101 );
+    assert_eq!( clean, dirty );
+
+    let mut map = HashMap::new();
+    map.insert(dirty, "cell");
+    assert_eq!( map.get(&clean), Some(&"cell") );
+    assert_eq!( map.get(&Bitset::<4>(0b_0110_0101)), Some(&"cell") );
+    assert_eq!( map.get(&Bitset::<4>(0b_0000_0100)), None );
+}
+
 #[test] fn into_iter()
 {
     assert_eq!( byteset![].into_iter().collect_vec(), vec![] );
     assert_eq!( byteset![1;8].into_iter().collect_vec(), (1..=8).rev().collect_vec() );
 }
+
+#[test] fn iter_ref()
+{
+    fn collect(bitset: &Bitset<8>) -> Vec<usize> {
+        bitset.iter_ref().collect_vec()
+    }
+
+    assert_eq!( collect(&byteset![]), vec![] );
+    assert_eq!( collect(&byteset![1,3,8]), vec![8,3,1] );
+}
+
+#[test] fn into_iter_fused()
+{
+    let mut iter = byteset![].into_iter();
+    for _ in 0..4 { assert_eq!( iter.next(), None ); }
+
+    let mut iter = byteset![2,8].into_iter();
+    assert_eq!( iter.next(), Some(8) );
+    assert_eq!( iter.next(), Some(2) );
+    for _ in 0..12 { assert_eq!( iter.next(), None ); }
+
+    let mut iter = byteset![1].into_iter().fuse();
+    assert_eq!( iter.next(), Some(1) );
+    assert_eq!( iter.next(), None );
+    assert_eq!( iter.next(), None );
+}
+
+#[test] fn iter_to()
+{
+    let bitset = byteset![1,3,5,8];
+    assert_eq!( bitset.iter_to(5).collect_vec(), vec![5,3,1] );
+    assert_eq!( bitset.iter_to(8).collect_vec(), vec![8,5,3,1] );
+    assert_eq!( bitset.iter_to(99).collect_vec(), vec![8,5,3,1] );
+    assert_eq!( bitset.iter_to(2).collect_vec(), vec![1] );
+    assert_eq!( bitset.iter_to(0).collect_vec(), vec![] );
+    assert_eq!( bitset.iter_to(-3).collect_vec(), vec![] );
+}
+
+#[test] fn iter_indexed()
+{
+    assert_eq!( byteset![].iter_indexed().collect_vec(), vec![] );
+    assert_eq!( byteset![1,3].iter_indexed().collect_vec(), vec![(3, 0b_0100), (1, 0b_0001)] );
+}
+
+#[test] fn extend()
+{
+    let mut bitset = byteset![1];
+    bitset.extend(vec![byteset![], byteset![2,3], byteset![3,8]]);
+    assert_eq!( bitset, byteset![1,2,3,8] );
+
+    let mut bitset = byteset![1];
+    bitset.extend(byteset![4,5].iter());
+    assert_eq!( bitset, byteset![1,4,5] );
+
+    let mut bitset = byteset![];
+    bitset.extend([2, 6, 99]);
+    assert_eq!( bitset, byteset![2,6] );
+}
+
+#[test] fn flags()
+{
+    assert_eq!( Bitset::<4>::none().flags().collect_vec(), vec![false; 4] );
+    assert_eq!( Bitset::<4>::from([1,3]).flags().collect_vec(), vec![true, false, true, false] );
+    assert_eq!( byteset![1;8].flags().collect_vec(), vec![true; 8] );
+}
+
+#[test] fn as_flags()
+{
+    assert_eq!( byteset![].as_flags().into_iter().collect_vec(), vec![] );
+    assert_eq!( byteset![1,3].as_flags().into_iter().collect_vec(), vec![0b_0100, 0b_0001] );
+    assert_eq!( byteset![1;8].as_flags().into_iter().sum::<u8>(), 0b_1111_1111 );
+}
+
+#[test] fn bit_indices()
+{
+    assert_eq!( byteset![].bit_indices().collect_vec(), vec![] );
+    assert_eq!( byteset![1,3].bit_indices().collect_vec(), vec![0, 2] );
+    assert_eq!( byteset![1;8].bit_indices().collect_vec(), (0..8).collect_vec() );
+}
+
+#[test] fn member_at_bit()
+{
+    assert_eq!( Bitset::<8>::member_at_bit(0), Some(1) );
+    assert_eq!( Bitset::<8>::member_at_bit(7), Some(8) );
+    assert_eq!( Bitset::<8>::member_at_bit(8), None );
+    assert_eq!( Bitset::<0>::member_at_bit(0), None );
+}
+
+#[test] fn bit_of_member()
+{
+    assert_eq!( Bitset::<8>::bit_of_member(1), Some(0) );
+    assert_eq!( Bitset::<8>::bit_of_member(8), Some(7) );
+    assert_eq!( Bitset::<8>::bit_of_member(0), None );
+    assert_eq!( Bitset::<8>::bit_of_member(9), None );
+
+    for bit in 0..8 {
+        assert_eq!( Bitset::<8>::member_at_bit(bit).and_then(Bitset::<8>::bit_of_member), Some(bit) );
+    }
+}
+
+#[test] fn universe_array()
+{
+    const UNIVERSE: [usize; 4] = Bitset::<4>::universe_array();
+    assert_eq!( UNIVERSE, [1,2,3,4] );
+
+    assert_eq!( Bitset::<0>::universe_array(), [] );
+    assert_eq!( Bitset::<8>::universe_array().to_vec(), Bitset::<8>::all().members_asc() );
+}
+
+#[test] fn accessors_ignore_dirty_bits()
+{
+    for dirty in [Bitset::<4>(0b_1010_0101), Bitset::<4>(0b_1111_0101), Bitset::<4>(0b_1000_0101)] {
+        assert_eq!( dirty.iter().collect_vec(), vec![3,1] );
+        assert_eq!( dirty.iter_ref().collect_vec(), vec![3,1] );
+        assert_eq!( dirty.members_asc(), vec![1,3] );
+        assert_eq!( dirty.members_desc(), vec![3,1] );
+        assert_eq!( dirty.members(), [1,3].into() );
+        assert_eq!( dirty.len(), 2 );
+        assert_eq!( dirty.bit_indices().collect_vec(), vec![0,2] );
+
+        let mut visited = vec![];
+        let mut copy = dirty;
+        copy.retain(|n| { visited.push(n); true });
+        assert_eq!( visited, vec![3,1] );
+    }
+
+    let dirty = Bitset::<4>(0b_1111_0000);
+    assert_eq!( dirty.iter().count(), 0 );
+    assert!( dirty.members_asc().is_empty() );
+    assert_eq!( dirty.len(), 0 );
+}
+
+#[test] fn queries_ignore_dirty_bits()
+{
+    assert!( Bitset::<4>(0b_0001_0001).len_is_odd() );
+    assert!( !Bitset::<4>(0b_0001_0000).len_is_odd() );
+
+    assert!( Bitset::<4>(0b_0001_0000).is_empty() );
+    assert!( !Bitset::<4>(0b_0001_0001).is_empty() );
+
+    assert!( Bitset::<4>(0b_0001_0001).is_subset(&Bitset::<4>(0b_0011)) );
+    assert!( Bitset::<4>(0b_0011).is_superset(&Bitset::<4>(0b_0001_0001)) );
+    assert!( Bitset::<4>(0b_0001_0001) < Bitset::<4>(0b_0011) );
+    assert!( Bitset::<4>(0b_0001_0001).is_disjoint(&Bitset::<4>(0b_0001_0010)) );
+
+    assert_eq!( Bitset::<4>(0b_0001_0011).to_range(), Some(1..=2) );
+    assert!( Bitset::<4>(0b_0001_0011).is_consecutive_from(1) );
+    assert_eq!( Bitset::<4>(0b_1111_0101).by_parity(), (Bitset::<4>::from([1,3]), Bitset::<4>::none()) );
+    assert_eq!( *Bitset::<4>(0b_1111_0101).by_parity().1, 0 );
+}
+
+#[test] fn mutations_ignore_dirty_bits()
+{
+    let mut bitset = Bitset::<4>(0b_1111_0011);
+    bitset.retain_top_k_by(2, |n| n as i64);
+    assert_eq!( bitset, Bitset::<4>::from([1,2]) );
+
+    let mut bitset = Bitset::<4>(0b_1111_0011);
+    bitset.retain_top_k_by(1, |n| n as i64);
+    assert_eq!( bitset, Bitset::<4>::from([2]) );
+
+    let mut bitset = Bitset::<4>(0b_1111_0001);
+    bitset.fill_to_len(3);
+    assert_eq!( bitset, Bitset::<4>::from([1,2,3]) );
+
+    let mut bitset = Bitset::<4>(0b_1111_0001);
+    bitset.truncate_to_len(2);
+    assert_eq!( *bitset, 0b_0001 );
+}